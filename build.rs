@@ -1,61 +1,82 @@
-use std::{fs};
-
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Discovers every `src/yearYYYY/dayNN/mod.rs` and writes into `OUT_DIR`
+/// - `days.rs`: the `yearYYYY` modules with their days, included by `lib.rs`
+/// - `registry.rs`: the `registry!` invocation, included by `registry.rs`
+///
+/// so adding a day or a year needs neither a `mod` declaration nor a registry entry.
 fn main() {
-    let year = 2025;
-    let day_folder = format!("./src/year{}/", year);
-
-    let num_days = fs::read_dir(&day_folder)
-        .unwrap_or_else(|_| panic!("Unable to read directory {day_folder}"))
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.file_type().map(|ft| ft.is_dir()).unwrap_or(false))
-        .count();
+    let src = Path::new(&std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo")).join("src");
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
 
-    let main_rs = format!(
-        r#"use std::str::FromStr;
-use std::time::Instant;
-use crate::aoc::Day;
+    let years = discover(&src);
 
-pub mod year{year};
-pub mod utils;
-pub mod aoc;
+    let mut days_rs = String::new();
+    let mut registry_rs = String::from("registry! {\n");
 
-fn main() -> anyhow::Result<()> {{
-    type CurrentDay = year{year}::day{num_days:02}::Day;
+    for (year, days) in &years {
+        days_rs += &format!("pub mod year{year} {{\n");
+        registry_rs += &format!("    {year} => year{year} {{\n");
 
-    // running day in year
-    let mut day = CurrentDay::from_str(include_str!("./year{year}/day{num_days:02}/input.txt"))?;
+        for (day, path) in days {
+            days_rs += &format!("    #[path = {:?}]\n    pub mod day{day:02};\n", path.display().to_string());
+            registry_rs += &format!("        {day} => day{day:02},\n");
+        }
 
-    println!("Running Year {year} Day {num_days:02}");
+        days_rs += "}\n\n";
+        registry_rs += "    },\n";
+    }
 
-    day.test_1()?;
-    day.after_test_1();
-    println!("Test 1 passed");
-    let time = Instant::now();
-    println!("Solution 1: {{:<20}} took ~{{}}ms", day.solution1()?, (Instant::now() - time).as_millis());
+    registry_rs += "}\n";
 
-    day.test_2()?;
-    day.after_test_2();
-    println!("Test 2 passed");
-    let time = Instant::now();
-    println!("Solution 2: {{:<20}} took ~{{}}ms", day.solution2()?, (Instant::now() - time).as_millis());
+    write_if_changed(&out_dir.join("days.rs"), &days_rs);
+    write_if_changed(&out_dir.join("registry.rs"), &registry_rs);
 
-    Ok(())
-}}"#);
+    println!("cargo:rerun-if-changed=build.rs");
+    // only the year directories matter for discovery, edits elsewhere in src must not rerun this script
+    for year in years.keys() {
+        println!("cargo:rerun-if-changed=src/year{year}");
+    }
+}
 
-    let main_rs_path = "./src/main.rs".to_string();
-    let mod_rs_path = format!("{day_folder}mod.rs");
+fn discover(src: &Path) -> BTreeMap<u16, BTreeMap<u8, PathBuf>> {
+    let mut years = BTreeMap::new();
 
-    let mut mod_rs: String = String::new();
+    for (year, year_path) in numbered_dirs(src, "year") {
+        let days = numbered_dirs(&year_path, "day")
+            .filter(|(_, day_path)| day_path.join("mod.rs").is_file())
+            .map(|(day, day_path)| (day, day_path.join("mod.rs")))
+            .collect::<BTreeMap<u8, PathBuf>>();
 
-    for i in 1..=num_days {
-        mod_rs += &format!("pub mod day{i:02};\n");
+        if !days.is_empty() {
+            years.insert(year, days);
+        }
     }
 
+    years
+}
 
-    #[allow(clippy::expect_fun_call)]
-    fs::write(mod_rs_path, mod_rs).expect(&format!("Unable to write to year{year}/mod.rs"));
-    fs::write(main_rs_path, main_rs).expect("Unable to write to main.rs");
+/// Directories in `path` named `<prefix><number>`, e.g. `year2023` or `day07`.
+fn numbered_dirs<T: std::str::FromStr>(path: &Path, prefix: &str) -> impl Iterator<Item = (T, PathBuf)> {
+    let prefix = prefix.to_string();
 
-    println!("cargo:rerun-if-changed=./src/year{year}");
-    println!("cargo:rerun-if-changed=build.rs");
+    fs::read_dir(path)
+        .unwrap_or_else(|_| panic!("Unable to read directory {}", path.display()))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().map(|ft| ft.is_dir()).unwrap_or(false))
+        .filter_map(move |entry| {
+            let number = entry.file_name().to_str()?.strip_prefix(&prefix)?.parse().ok()?;
+            Some((number, entry.path()))
+        })
+}
+
+/// Only touches the file when its content changes, so unrelated builds do not recompile the crate.
+fn write_if_changed(path: &Path, content: &str) {
+    if fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return;
+    }
+
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write to {}", path.display()));
 }
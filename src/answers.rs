@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::aoc::Part;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{file}:{line}: {message}")]
+    Syntax { file: String, line: usize, message: String },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Accepted answers per year, day and part, stored next to the inputs as
+///
+/// ```toml
+/// [2023.17]
+/// part1 = "102"
+/// part2 = "94"
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u16, u8, Part), String>,
+}
+
+impl Answers {
+    /// Reads `path`, a missing file is treated as no recorded answers.
+    pub fn load(path: &Path) -> Result<Answers, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Answers::parse(&content, &path.display().to_string()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        Ok(fs::write(path, self.to_string())?)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: Part, answer: String) {
+        self.answers.insert((year, day, part), answer);
+    }
+
+    pub fn days(&self) -> Vec<(u16, u8)> {
+        let mut days = self.answers.keys().map(|(year, day, _)| (*year, *day)).collect::<Vec<_>>();
+        days.dedup();
+        days
+    }
+
+    fn parse(content: &str, file: &str) -> Result<Answers, Error> {
+        let mut answers = Answers::default();
+        let mut current_day = None;
+
+        for (index, line) in content.lines().enumerate() {
+            let syntax = |message: &str| Error::Syntax { file: file.to_string(), line: index + 1, message: message.to_string() };
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                let (year, day) = header.split_once('.').ok_or_else(|| syntax("expected a [year.day] header"))?;
+                current_day = Some((
+                    year.trim().parse::<u16>().map_err(|_| syntax("invalid year"))?,
+                    day.trim().parse::<u8>().map_err(|_| syntax("invalid day"))?,
+                ));
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| syntax("expected part1 = \"...\" or part2 = \"...\""))?;
+            let (year, day) = current_day.ok_or_else(|| syntax("answer outside of a [year.day] section"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(syntax(&format!("unknown key \"{key}\""))),
+            };
+
+            answers.set(year, day, part, unquote(value.trim()).ok_or_else(|| syntax("expected a quoted string"))?);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (year, day)) in self.days().into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "[{year}.{day:02}]")?;
+            for part in Part::ALL {
+                if let Some(answer) = self.get(year, day, part) {
+                    writeln!(f, "part{part} = {}", quote(answer))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+fn unquote(value: &str) -> Option<String> {
+    let value = value.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(char) = chars.next() {
+        result.push(match char {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                escaped @ ('\\' | '"') => escaped,
+                _ => return None,
+            },
+            char => char,
+        });
+    }
+
+    Some(result)
+}
@@ -12,34 +12,180 @@ pub trait Day: Default + FromStr + Clone + Sized {
     fn after_test_2(&mut self) { }
 
     fn test_1(&self) -> anyhow::Result<()> where <Self as FromStr>::Err: std::error::Error, <Self as FromStr>::Err: Send, <Self as FromStr>::Err: Sync, <Self as FromStr>::Err: 'static {
-        for (test_case, result) in Self::test_cases_1() {
-            let mut day = Self::from_str(test_case)?;
-            assert_eq!(format!("{}", day.solution1()?), format!("{}", result))
-        }
-
-        Ok(())
+        check_test_cases(Part::One, Self::test_cases_1(), Self::solution1)
     }
 
     fn test_2(&self) -> anyhow::Result<()> where <Self as FromStr>::Err: std::error::Error, <Self as FromStr>::Err: Send, <Self as FromStr>::Err: Sync, <Self as FromStr>::Err: 'static {
-        for (test_case, result) in Self::test_cases_2() {
-            let mut day = Self::from_str(test_case)?;
-            assert_eq!(format!("{}", day.solution2()?), format!("{}", result))
-        }
-
-        Ok(())
+        check_test_cases(Part::Two, Self::test_cases_2(), Self::solution2)
     }
 
     fn solution1(&mut self) -> anyhow::Result<Self::Output>;
     fn solution2(&mut self) -> anyhow::Result<Self::Output>;
 }
 
+fn check_test_cases<D>(part: Part, test_cases: Vec<(&'static str, D::Output)>, solution: fn(&mut D) -> anyhow::Result<D::Output>) -> anyhow::Result<()>
+where
+    D: Day,
+    <D as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    for (case, (test_case, result)) in test_cases.into_iter().enumerate() {
+        let failed = |actual: String| Error::TestFailed {
+            part,
+            case,
+            input: excerpt(test_case),
+            expected: result.to_string(),
+            actual,
+        };
+
+        let mut day = D::from_str(test_case).map_err(|error| failed(format!("parse error: {error}")))?;
+        let actual = solution(&mut day).map_err(|error| failed(format!("error: {error:#}")))?.to_string();
+
+        if actual != result.to_string() {
+            return Err(failed(actual).into());
+        }
+    }
+
+    Ok(())
+}
+
+/// The first few lines of an example input, shortened so the error stays readable.
+fn excerpt(input: &str) -> String {
+    const LINES: usize = 3;
+    const WIDTH: usize = 60;
+
+    let mut lines = input.lines().take(LINES).map(|line| match line.char_indices().nth(WIDTH) {
+        Some((index, _)) => format!("{}...", &line[..index]),
+        None => line.to_string(),
+    }).collect::<Vec<_>>();
+
+    if input.lines().count() > LINES {
+        lines.push(String::from("..."));
+    }
+
+    lines.join("\n")
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
 
 
 #[derive(Debug)]
 pub enum Error {
     Parse(String),
     GridError(crate::utils::grid::Error),
-    NoSolutionFound
+    NoSolutionFound,
+    TestFailed { part: Part, case: usize, input: String, expected: String, actual: String },
+    /// `error` raised at `location` of the input, optionally in the day that parsed it.
+    Located { error: Box<Error>, location: Location, day: Option<(u16, u8)> },
+}
+
+/// Position of a span inside a puzzle input, rendered as a caret diagnostic by [`Error::Located`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Location {
+    /// Byte offset of the span into the input.
+    pub offset: usize,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The whole line containing the span.
+    pub snippet: String,
+    /// Width of the span in characters, at least 1.
+    pub length: usize,
+}
+
+impl Location {
+    /// Location of `span` in `input`. `span` is expected to be a subslice of `input`, like the pieces returned by
+    /// `lines()` or `split()`, otherwise its first occurrence is used.
+    pub fn of(input: &str, span: &str) -> Location {
+        let start = input.as_ptr() as usize;
+        let offset = match (span.as_ptr() as usize).checked_sub(start) {
+            Some(offset) if offset + span.len() <= input.len() => offset,
+            _ => input.find(span).unwrap_or(0),
+        };
+
+        Location::at(input, offset, span.chars().count())
+    }
+
+    /// Location of the byte `offset` in `input`, highlighting `length` characters.
+    pub fn at(input: &str, offset: usize, length: usize) -> Location {
+        let offset = offset.min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        let snippet = input[line_start..line_end].trim_end_matches('\r').to_string();
+        let column = input[line_start..offset].chars().count() + 1;
+
+        Location {
+            offset,
+            line: before.matches('\n').count() + 1,
+            column,
+            length: length.clamp(1, (snippet.chars().count() + 1).saturating_sub(column).max(1)),
+            snippet,
+        }
+    }
+}
+
+impl Error {
+    /// A parse error pointing at `span` inside `input`.
+    pub fn at(message: impl Into<String>, input: &str, span: &str) -> Error {
+        Error::Located { error: Box::new(Error::Parse(message.into())), location: Location::of(input, span), day: None }
+    }
+
+    /// Moves the location of an error raised while parsing `span` into `input`, so parsers of a single line or
+    /// token can report positions relative to what they were given. Errors without a location point at `span`.
+    pub fn within(self, input: &str, span: &str) -> Error {
+        let outer = Location::of(input, span);
+
+        match self {
+            Error::Located { error, location, day } => Error::Located {
+                error,
+                location: Location::at(input, outer.offset + location.offset, location.length),
+                day,
+            },
+            error => Error::Located { error: Box::new(error), location: outer, day: None },
+        }
+    }
+
+    /// Names the day that raised a located error in its diagnostic.
+    pub fn in_day(self, year: u16, day: u8) -> Error {
+        match self {
+            Error::Located { error, location, .. } => Error::Located { error, location, day: Some((year, day)) },
+            error => error,
+        }
+    }
+}
+
+/// [`Error::within`] for results, e.g. `value.parse::<u32>().within(input, value)?`.
+pub trait Context<T> {
+    fn within(self, input: &str, span: &str) -> Result<T, Error>;
+}
+
+impl<T, E: Into<Error>> Context<T> for Result<T, E> {
+    fn within(self, input: &str, span: &str) -> Result<T, Error> {
+        self.map_err(|error| error.into().within(input, span))
+    }
 }
 
 impl From<crate::utils::grid::Error> for Error {
@@ -65,7 +211,21 @@ impl Display for Error {
         write!(f, "{}", match self {
             Error::Parse(message) => format!("Cannot parse message: \"{message}\""),
             Error::NoSolutionFound => "No solution has been found".to_string(),
-            Error::GridError(v) => format!("{}", v)
+            Error::GridError(v) => format!("{}", v),
+            Error::TestFailed { part, case, input, expected, actual } => format!(
+                "Example {case} of part {part} failed\n    input: {}\n expected: {expected}\n   actual: {actual}",
+                input.replace('\n', "\n           ")
+            ),
+            Error::Located { error, location, day } => {
+                let gutter = " ".repeat(location.line.to_string().len());
+                let day = day.map(|(year, day)| format!("Year {year} Day {day:02}, ")).unwrap_or_default();
+
+                format!(
+                    "{error}\n{gutter}--> {day}line {}, column {}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+                    location.line, location.column, location.line, location.snippet,
+                    " ".repeat(location.column - 1), "^".repeat(location.length)
+                )
+            }
         })
     }
 }
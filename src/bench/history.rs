@@ -0,0 +1,125 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::Measurement;
+
+pub const HISTORY_FILE: &str = "bench-history.jsonl";
+pub const DEFAULT_MAX_REGRESSION: u32 = 10;
+
+/// One line of the benchmark history, a [`Measurement`] together with the commit it was taken at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub stage: String,
+    pub commit: String,
+    pub timestamp: u64,
+    pub iterations: u32,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Record {
+    pub fn new(measurement: &Measurement, commit: &str, iterations: u32) -> Record {
+        Record {
+            year: measurement.year,
+            day: measurement.day,
+            stage: measurement.stage.to_string(),
+            commit: commit.to_string(),
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs()),
+            iterations,
+            min: measurement.stats.min,
+            median: measurement.stats.median,
+            mean: measurement.stats.mean,
+            stddev: measurement.stats.stddev,
+        }
+    }
+
+    fn same_stage(&self, measurement: &Measurement) -> bool {
+        self.year == measurement.year && self.day == measurement.day && self.stage == measurement.stage.to_string()
+    }
+}
+
+/// Median of a stage compared to its latest recorded run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub previous: Record,
+    /// Relative change of the median in percent, positive means slower.
+    pub change: f64,
+}
+
+impl Comparison {
+    pub fn regressed(&self, max_regression: u32) -> bool {
+        self.change > f64::from(max_regression)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Reads a JSON lines file, a missing file is an empty history.
+    pub fn load(path: &Path) -> anyhow::Result<History> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(error) => return Err(error.into()),
+        };
+
+        let records = content.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| serde_json::from_str(line)
+                .map_err(|error| anyhow::anyhow!("{}:{}: {error}", path.display(), index + 1)))
+            .collect::<anyhow::Result<Vec<Record>>>()?;
+
+        Ok(History { records })
+    }
+
+    pub fn append(path: &Path, records: &[Record]) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        for record in records {
+            writeln!(file, "{}", serde_json::to_string(record)?)?;
+        }
+
+        Ok(())
+    }
+
+    /// Compares with the latest record of the same stage, `None` if there is none or its median was below the timer
+    /// resolution, as no relative change can be computed from 0.
+    pub fn compare(&self, measurement: &Measurement) -> Option<Comparison> {
+        let previous = self.records.iter().rev()
+            .find(|record| record.same_stage(measurement))
+            .filter(|previous| previous.median > 0.0)?;
+
+        Some(Comparison {
+            previous: previous.clone(),
+            change: (measurement.stats.median / previous.median - 1.0) * 100.0,
+        })
+    }
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` for uncommitted changes, or `unknown` outside of git.
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty", "--abbrev=10"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
@@ -0,0 +1,152 @@
+mod history;
+
+use std::time::{Duration, Instant};
+
+use anyhow::bail;
+
+use crate::aoc::Part;
+use crate::cli::RunArgs;
+use crate::input::InputProvider;
+use crate::registry::Entry;
+use crate::runner;
+
+pub use history::{current_commit, Comparison, History, Record, DEFAULT_MAX_REGRESSION, HISTORY_FILE};
+
+pub const DEFAULT_ITERATIONS: u32 = 20;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl std::fmt::Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+/// Timings of a single stage in microseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut micros = samples.iter().map(|sample| sample.as_secs_f64() * 1_000_000.0).collect::<Vec<_>>();
+        micros.sort_by(f64::total_cmp);
+
+        let n = micros.len() as f64;
+        let mean = micros.iter().sum::<f64>() / n;
+        let median = match micros.len() % 2 {
+            0 => (micros[micros.len() / 2 - 1] + micros[micros.len() / 2]) / 2.0,
+            _ => micros[micros.len() / 2],
+        };
+
+        Stats {
+            min: micros[0],
+            median,
+            mean,
+            stddev: (micros.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+pub fn bench(args: &RunArgs) -> anyhow::Result<()> {
+    let inputs = InputProvider::new(args.input.clone(), args.input_dir.clone());
+    let iterations = args.iterations.unwrap_or(DEFAULT_ITERATIONS);
+    let mut measurements = Vec::new();
+
+    for entry in runner::select(args)? {
+        measurements.extend(bench_day(&entry, &inputs, &runner::parts(args), iterations)?);
+    }
+
+    let history_path = args.history.clone().unwrap_or_else(|| inputs.directory().join(HISTORY_FILE));
+    let history = History::load(&history_path)?;
+    let max_regression = args.max_regression.unwrap_or(DEFAULT_MAX_REGRESSION);
+    let commit = current_commit();
+    let mut regressions = Vec::new();
+
+    println!("{iterations} iterations at {commit}, times in µs");
+    println!(
+        "{:<6} {:<4} {:<8} {:>12} {:>12} {:>12} {:>12} {:>10}",
+        "Year", "Day", "Stage", "min", "median", "mean", "stddev", "vs. last"
+    );
+    for measurement @ Measurement { year, day, stage, stats } in &measurements {
+        let comparison = history.compare(measurement);
+        let change = match &comparison {
+            Some(comparison) if comparison.regressed(max_regression) => format!("{:+.1}% !", comparison.change),
+            Some(comparison) => format!("{:+.1}%", comparison.change),
+            None => String::from("-"),
+        };
+
+        println!(
+            "{:<6} {:<4} {:<8} {:>12.1} {:>12.1} {:>12.1} {:>12.1} {:>10}",
+            year, format!("{day:02}"), stage.to_string(), stats.min, stats.median, stats.mean, stats.stddev, change
+        );
+
+        if let Some(comparison) = comparison.filter(|comparison| comparison.regressed(max_regression)) {
+            regressions.push(format!(
+                "Year {year} Day {day:02} {stage}: median {:.1}µs -> {:.1}µs ({:+.1}%) since {}",
+                comparison.previous.median, stats.median, comparison.change, comparison.previous.commit
+            ));
+        }
+    }
+
+    let records = measurements.iter().map(|measurement| Record::new(measurement, &commit, iterations)).collect::<Vec<_>>();
+    History::append(&history_path, &records)?;
+
+    if !regressions.is_empty() {
+        bail!("{} stages got more than {max_regression}% slower:\n{}", regressions.len(), regressions.join("\n"));
+    }
+
+    Ok(())
+}
+
+/// Times parsing and every part separately. Each sample parses the day once and solves the parts in order on that
+/// instance, as `run` does, since solutions may mutate their state. One extra untimed round warms up caches and
+/// allocations.
+pub fn bench_day(entry: &Entry, inputs: &InputProvider, parts: &[Part], iterations: u32) -> anyhow::Result<Vec<Measurement>> {
+    let input = inputs.load(entry.year, entry.day)?;
+    let measurement = |stage, samples: Vec<Duration>| Measurement { year: entry.year, day: entry.day, stage, stats: Stats::new(&samples[1..]) };
+
+    let mut samples = Vec::new();
+    for _ in 0..=iterations {
+        let time = Instant::now();
+        entry.parse(&input)?;
+        samples.push(time.elapsed());
+    }
+
+    let mut part_samples = vec![Vec::new(); parts.len()];
+    for _ in 0..=iterations {
+        let mut day = entry.parse(&input)?;
+
+        for (&part, samples) in parts.iter().zip(&mut part_samples) {
+            day.after_test(part);
+
+            let time = Instant::now();
+            day.solve(part)?;
+            samples.push(time.elapsed());
+        }
+    }
+
+    let mut measurements = vec![measurement(Stage::Parse, samples)];
+    measurements.extend(parts.iter().zip(part_samples).map(|(&part, samples)| measurement(Stage::Solve(part), samples)));
+
+    Ok(measurements)
+}
@@ -0,0 +1,186 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail};
+
+use crate::aoc::Part;
+use crate::report::Format;
+use crate::scaffold::OUTPUT_TYPES;
+
+pub const USAGE: &str = "Usage:
+    aoc run [--year <YEAR>] [--day <DAY>] [--part <1|2>] [--input <FILE>] [--format <text|json|csv>]
+    aoc run --year <YEAR> --all [--input-dir <DIR>] [--format <text|json|csv>]
+    aoc all [--year <YEAR>] [--part <1|2>] [--input-dir <DIR>] [--format <text|json|csv>]
+    aoc verify [--year <YEAR>] [--day <DAY>] [--part <1|2>] [--input-dir <DIR>]
+    aoc bench [--year <YEAR>] [--day <DAY> | --all] [--part <1|2>] [--iterations <N>] [--input-dir <DIR>]
+              [--history <FILE>] [--max-regression <PERCENT>]
+    aoc new-day --year <YEAR> --day <DAY> [--output <TYPE>]
+    aoc help
+
+Without arguments the latest registered day is run.
+Inputs are read from <DIR>/<YEAR>/<DAY>.txt, where <DIR> defaults to $AOC_INPUT_DIR or ./inputs.
+`all` runs every registered day (of <YEAR>) in parallel, RAYON_NUM_THREADS limits the threads.
+`run --record` stores the answers in <DIR>/answers.toml, `verify` re-runs every day with a
+recorded answer and reports mismatches.
+`bench` times parsing and each part separately, 20 iterations unless --iterations is given. Results are
+appended to <DIR>/bench-history.jsonl and medians more than 10% (--max-regression) slower than the previous
+run are reported as regressions.
+`new-day` creates src/year<YEAR>/day<DAY>/mod.rs with an Output of <TYPE> (usize by default), build.rs
+discovers and registers it.";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    All(RunArgs),
+    Verify(RunArgs),
+    NewDay(NewDayArgs),
+    Bench(RunArgs),
+    Help,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct RunArgs {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub all: bool,
+    pub record: bool,
+    pub format: Format,
+    pub iterations: Option<u32>,
+    pub history: Option<PathBuf>,
+    pub max_regression: Option<u32>,
+    pub input: Option<PathBuf>,
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NewDayArgs {
+    pub year: u16,
+    pub day: u8,
+    pub output: String,
+}
+
+impl Command {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> anyhow::Result<Command> {
+        let mut args = args.into_iter();
+
+        match args.next().as_deref() {
+            None => Ok(Command::Run(RunArgs::default())),
+            Some("run") => {
+                let args = RunArgs::parse(args)?;
+                if args.bench_only() {
+                    bail!("--iterations, --history and --max-regression are only used by bench");
+                }
+
+                Ok(Command::Run(args))
+            }
+            Some("all") => {
+                let args = RunArgs::parse(args)?;
+                if args.day.is_some() || args.all || args.record || args.input.is_some() || args.bench_only() {
+                    bail!("all only accepts --year, --part and --input-dir");
+                }
+
+                Ok(Command::All(args))
+            }
+            Some("verify") => {
+                let args = RunArgs::parse(args)?;
+                if args.all || args.record || args.input.is_some() || args.format != Format::Text || args.bench_only() {
+                    bail!("verify only accepts --year, --day, --part and --input-dir");
+                }
+
+                Ok(Command::Verify(args))
+            }
+            Some("bench") => {
+                let args = RunArgs::parse(args)?;
+                if args.record || args.format != Format::Text {
+                    bail!("--record and --format cannot be combined with bench");
+                }
+
+                Ok(Command::Bench(args))
+            }
+            Some("new-day") => Ok(Command::NewDay(NewDayArgs::parse(args)?)),
+            Some("help" | "--help" | "-h") => Ok(Command::Help),
+            Some(command) => bail!("Unknown command \"{command}\"\n\n{USAGE}"),
+        }
+    }
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> anyhow::Result<RunArgs> {
+        let mut run_args = RunArgs::default();
+
+        while let Some(flag) = args.next() {
+            match flag.as_str() {
+                "--all" => run_args.all = true,
+                "--record" => run_args.record = true,
+                "--format" | "-f" => run_args.format = value(&flag, args.next())?,
+                "--history" => run_args.history = Some(value(&flag, args.next())?),
+                "--max-regression" => run_args.max_regression = Some(value(&flag, args.next())?),
+                "--iterations" | "-n" => run_args.iterations = Some(match value(&flag, args.next())? {
+                    0 => bail!("--iterations must be at least 1"),
+                    iterations => iterations,
+                }),
+                "--input" | "-i" => run_args.input = Some(value(&flag, args.next())?),
+                "--input-dir" => run_args.input_dir = Some(value(&flag, args.next())?),
+                "--year" | "-y" => run_args.year = Some(value(&flag, args.next())?),
+                "--day" | "-d" => run_args.day = Some(value(&flag, args.next())?),
+                "--part" | "-p" => run_args.part = Some(match value::<u8>(&flag, args.next())? {
+                    1 => Part::One,
+                    2 => Part::Two,
+                    part => bail!("Part must be 1 or 2, got {part}"),
+                }),
+                _ => bail!("Unknown argument \"{flag}\"\n\n{USAGE}"),
+            }
+        }
+
+        if run_args.all && run_args.day.is_some() {
+            bail!("--all and --day cannot be combined");
+        }
+
+        if run_args.all && run_args.input.is_some() {
+            bail!("--input selects a single file and cannot be combined with --all, use --input-dir instead");
+        }
+
+        Ok(run_args)
+    }
+
+    fn bench_only(&self) -> bool {
+        self.iterations.is_some() || self.history.is_some() || self.max_regression.is_some()
+    }
+}
+
+impl NewDayArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> anyhow::Result<NewDayArgs> {
+        let (mut year, mut day, mut output) = (None, None, String::from("usize"));
+
+        while let Some(flag) = args.next() {
+            match flag.as_str() {
+                "--year" | "-y" => year = Some(value(&flag, args.next())?),
+                "--day" | "-d" => day = Some(value(&flag, args.next())?),
+                "--output" | "-o" => output = value(&flag, args.next())?,
+                _ => bail!("Unknown argument \"{flag}\"\n\n{USAGE}"),
+            }
+        }
+
+        let year = year.ok_or_else(|| anyhow!("new-day needs --year"))?;
+        let day = day.ok_or_else(|| anyhow!("new-day needs --day"))?;
+
+        if !(2015..=9999).contains(&year) {
+            bail!("Year must be 2015 or later, got {year}");
+        }
+
+        if !(1..=25).contains(&day) {
+            bail!("Day must be between 1 and 25, got {day}");
+        }
+
+        if !OUTPUT_TYPES.contains(&output.as_str()) {
+            bail!("Output must be one of {}, got \"{output}\"", OUTPUT_TYPES.join(", "));
+        }
+
+        Ok(NewDayArgs { year, day, output })
+    }
+}
+
+fn value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> anyhow::Result<T> {
+    let value = value.ok_or_else(|| anyhow!("Missing value for {flag}"))?;
+    value.parse().map_err(|_| anyhow!("Invalid value \"{value}\" for {flag}"))
+}
@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+/// Environment variable pointing to a directory laid out as `<year>/<day>.txt`.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("No input for Year {year} Day {day:02}, looked at: {}", display_paths(.searched))]
+    Missing { year: u16, day: u8, searched: Vec<PathBuf> },
+    #[error("Unable to read input {}: {source}", .path.display())]
+    Unreadable { path: PathBuf, source: std::io::Error },
+}
+
+fn display_paths(paths: &[PathBuf]) -> String {
+    paths.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// Resolves puzzle inputs at runtime.
+///
+/// An explicit file always wins. Otherwise the input directory is taken from the command line, then from
+/// [`INPUT_DIR_ENV`] and finally [`DEFAULT_INPUT_DIR`]. When no directory was configured, the legacy
+/// `src/yearYYYY/dayNN/input.txt` location is checked as well.
+#[derive(Debug, Default, Clone)]
+pub struct InputProvider {
+    file: Option<PathBuf>,
+    directory: Option<PathBuf>,
+}
+
+impl InputProvider {
+    pub fn new(file: Option<PathBuf>, directory: Option<PathBuf>) -> Self {
+        Self {
+            file,
+            directory: directory.or_else(|| std::env::var_os(INPUT_DIR_ENV).map(PathBuf::from)),
+        }
+    }
+
+    pub fn candidates(&self, year: u16, day: u8) -> Vec<PathBuf> {
+        if let Some(file) = &self.file {
+            return vec![file.clone()];
+        }
+
+        match &self.directory {
+            Some(directory) => vec![day_path(directory, year, day)],
+            None => vec![
+                day_path(Path::new(DEFAULT_INPUT_DIR), year, day),
+                PathBuf::from(format!("src/year{year}/day{day:02}/input.txt")),
+            ],
+        }
+    }
+
+    /// The configured input directory, or [`DEFAULT_INPUT_DIR`].
+    pub fn directory(&self) -> &Path {
+        self.directory.as_deref().unwrap_or(Path::new(DEFAULT_INPUT_DIR))
+    }
+
+    /// `answers.toml` inside the input directory, see [`crate::answers`].
+    pub fn answers_path(&self) -> PathBuf {
+        self.directory().join(crate::answers::ANSWERS_FILE)
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, Error> {
+        let searched = self.candidates(year, day);
+
+        match searched.iter().find(|path| path.is_file()) {
+            Some(path) => fs::read_to_string(path).map_err(|source| Error::Unreadable { path: path.clone(), source }),
+            None => Err(Error::Missing { year, day, searched }),
+        }
+    }
+}
+
+fn day_path(directory: &Path, year: u16, day: u8) -> PathBuf {
+    directory.join(year.to_string()).join(format!("{day:02}.txt"))
+}
@@ -0,0 +1,13 @@
+// `pub mod yearYYYY { pub mod dayNN; }` for every day found by build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+pub mod utils;
+pub mod aoc;
+pub mod bench;
+pub mod answers;
+pub mod cli;
+pub mod input;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
@@ -1,30 +1,16 @@
-use std::str::FromStr;
-use std::time::Instant;
-use crate::aoc::Day;
-
-pub mod year2025;
-pub mod utils;
-pub mod aoc;
+use advent_of_code::cli::{Command, USAGE};
+use advent_of_code::{bench, runner, scaffold};
 
 fn main() -> anyhow::Result<()> {
-    type CurrentDay = year2025::day10::Day;
-
-    // running day in year
-    let mut day = CurrentDay::from_str(include_str!("./year2025/day10/input.txt"))?;
-
-    println!("Running Year 2025 Day 10");
-
-    day.test_1()?;
-    day.after_test_1();
-    println!("Test 1 passed");
-    let time = Instant::now();
-    println!("Solution 1: {:<20} took ~{}ms", day.solution1()?, (Instant::now() - time).as_millis());
-
-    day.test_2()?;
-    day.after_test_2();
-    println!("Test 2 passed");
-    let time = Instant::now();
-    println!("Solution 2: {:<20} took ~{}ms", day.solution2()?, (Instant::now() - time).as_millis());
-
-    Ok(())
-}
\ No newline at end of file
+    match Command::parse(std::env::args().skip(1))? {
+        Command::Run(args) => runner::run(&args),
+        Command::All(args) => runner::run_parallel(&args),
+        Command::Verify(args) => runner::verify(&args),
+        Command::NewDay(args) => scaffold::new_day(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(())
+        }
+    }
+}
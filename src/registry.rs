@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use crate::aoc::{Day, Part};
+
+/// Object safe view on a parsed [`Day`], so days with different `Output` types can live in the same registry.
+pub trait Solution {
+    fn test(&self, part: Part) -> anyhow::Result<()>;
+    fn after_test(&mut self, part: Part);
+    fn solve(&mut self, part: Part) -> anyhow::Result<String>;
+}
+
+impl<D> Solution for D
+where
+    D: Day,
+    <D as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    fn test(&self, part: Part) -> anyhow::Result<()> {
+        match part {
+            Part::One => self.test_1(),
+            Part::Two => self.test_2(),
+        }
+    }
+
+    fn after_test(&mut self, part: Part) {
+        match part {
+            Part::One => self.after_test_1(),
+            Part::Two => self.after_test_2(),
+        }
+    }
+
+    fn solve(&mut self, part: Part) -> anyhow::Result<String> {
+        Ok(match part {
+            Part::One => self.solution1()?.to_string(),
+            Part::Two => self.solution2()?.to_string(),
+        })
+    }
+}
+
+/// One of the examples from [`Day::test_cases_1`] or [`Day::test_cases_2`], with the expected output already formatted.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub input: &'static str,
+    pub expected: String,
+}
+
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> anyhow::Result<Box<dyn Solution>>,
+    examples: fn(Part) -> Vec<Example>,
+}
+
+impl Entry {
+    fn new<D>(year: u16, day: u8) -> Self
+    where
+        D: Day + 'static,
+        <D as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+    {
+        Self {
+            year,
+            day,
+            parse: |input| Ok(Box::new(D::from_str(input)?)),
+            examples: |part| {
+                let test_cases = match part {
+                    Part::One => D::test_cases_1(),
+                    Part::Two => D::test_cases_2(),
+                };
+
+                test_cases.into_iter()
+                    .map(|(input, expected)| Example { input, expected: expected.to_string() })
+                    .collect()
+            },
+        }
+    }
+
+    /// Parses `input`, naming this day in located [`crate::aoc::Error`]s.
+    pub fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Solution>> {
+        (self.parse)(input).map_err(|error| match error.downcast::<crate::aoc::Error>() {
+            Ok(error) => error.in_day(self.year, self.day).into(),
+            Err(error) => error,
+        })
+    }
+
+    pub fn examples(&self, part: Part) -> Vec<Example> {
+        (self.examples)(part)
+    }
+
+    /// Solves `input` on a freshly parsed day, the same way [`Day::test_1`] and [`Day::test_2`] do.
+    pub fn solve_example(&self, part: Part, input: &str) -> anyhow::Result<String> {
+        self.parse(input)?.solve(part)
+    }
+}
+
+macro_rules! registry {
+    ($($year:literal => $year_module:ident { $($day:literal => $day_module:ident),* $(,)? }),* $(,)?) => {
+        /// Every registered day, ordered by year and day.
+        pub fn all() -> Vec<Entry> {
+            vec![$($(Entry::new::<crate::$year_module::$day_module::Day>($year, $day)),*),*]
+        }
+    };
+}
+
+// generated by build.rs from the `src/yearYYYY/dayNN` directories
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+pub fn find(year: u16, day: u8) -> Option<Entry> {
+    all().into_iter().find(|entry| entry.year == year && entry.day == day)
+}
+
+pub fn year(year: u16) -> Vec<Entry> {
+    all().into_iter().filter(|entry| entry.year == year).collect()
+}
+
+pub fn latest() -> Option<Entry> {
+    all().into_iter().max_by_key(|entry| (entry.year, entry.day))
+}
@@ -0,0 +1,130 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::bail;
+use serde::Serialize;
+
+use crate::runner::{DayOutcome, DayReport};
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "text" => Format::Text,
+            "json" => Format::Json,
+            "csv" => Format::Csv,
+            _ => bail!("Format must be text, json or csv, got \"{s}\""),
+        })
+    }
+}
+
+/// One line of machine readable output, a part of a day or a day that could not be solved at all.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+    /// `ok`, `failed` or `skipped`
+    pub status: &'static str,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    /// `passed` or `failed`
+    pub example: Option<&'static str>,
+    pub example_error: Option<String>,
+    pub parse_us: Option<f64>,
+    pub solve_us: Option<f64>,
+}
+
+impl Row {
+    pub fn from_reports(reports: &[DayReport]) -> Vec<Row> {
+        reports.iter().flat_map(|report| {
+            let row = |status, error: &String| Row {
+                year: report.year,
+                day: report.day,
+                part: None,
+                status,
+                answer: None,
+                error: Some(error.clone()),
+                example: None,
+                example_error: None,
+                parse_us: None,
+                solve_us: None,
+            };
+
+            match &report.outcome {
+                DayOutcome::Skipped(reason) => vec![row("skipped", reason)],
+                DayOutcome::Failed(error) => vec![row("failed", error)],
+                DayOutcome::Solved { parse_time, parts } => parts.iter().map(|part| Row {
+                    year: report.year,
+                    day: report.day,
+                    part: Some(part.part.number()),
+                    status: if part.answer.is_ok() && part.example.is_ok() { "ok" } else { "failed" },
+                    answer: part.answer.as_ref().ok().cloned(),
+                    error: part.answer.as_ref().err().cloned(),
+                    example: Some(if part.example.is_ok() { "passed" } else { "failed" }),
+                    example_error: part.example.as_ref().err().cloned(),
+                    parse_us: Some(micros(*parse_time)),
+                    solve_us: Some(micros(part.time)),
+                }).collect(),
+            }
+        }).collect()
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
+
+/// Renders `reports` as a JSON array or a CSV table with a header, one [`Row`] each.
+pub fn render(reports: &[DayReport], format: Format) -> anyhow::Result<String> {
+    let rows = Row::from_reports(reports);
+
+    Ok(match format {
+        Format::Text => bail!("Text output is printed while running"),
+        Format::Json => serde_json::to_string_pretty(&rows)? + "\n",
+        Format::Csv => {
+            let mut csv = String::from("year,day,part,status,answer,error,example,example_error,parse_us,solve_us\n");
+
+            for row in rows {
+                let fields = [
+                    row.year.to_string(),
+                    row.day.to_string(),
+                    optional(row.part),
+                    row.status.to_string(),
+                    escape(&row.answer.unwrap_or_default()),
+                    escape(&row.error.unwrap_or_default()),
+                    optional(row.example),
+                    escape(&row.example_error.unwrap_or_default()),
+                    optional(row.parse_us.map(|us| format!("{us:.1}"))),
+                    optional(row.solve_us.map(|us| format!("{us:.1}"))),
+                ];
+
+                csv += &fields.join(",");
+                csv.push('\n');
+            }
+
+            csv
+        }
+    })
+}
+
+fn optional(value: Option<impl ToString>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Quotes fields containing separators, quotes or line breaks, like the 2022 Day 10 screen.
+fn escape(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
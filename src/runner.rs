@@ -0,0 +1,360 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use rayon::prelude::*;
+
+use crate::answers::Answers;
+use crate::aoc::Part;
+use crate::cli::RunArgs;
+use crate::input::{self, InputProvider};
+use crate::registry::{self, Entry};
+use crate::report::{self, Format};
+
+pub fn run(args: &RunArgs) -> anyhow::Result<()> {
+    let parts = parts(args);
+    let inputs = InputProvider::new(args.input.clone(), args.input_dir.clone());
+    let mut answers = Answers::default();
+    let mut failures = Vec::new();
+
+    if args.record {
+        answers = Answers::load(&inputs.answers_path())?;
+    }
+
+    if args.format == Format::Text {
+        for entry in select(args)? {
+            match run_day(&entry, &inputs, &parts, &mut failures) {
+                Ok(solved) => solved.into_iter().for_each(|(part, answer)| answers.set(entry.year, entry.day, part, answer)),
+                Err(error) => {
+                    println!("Year {} Day {:02} failed: {error:#}", entry.year, entry.day);
+                    failures.push(format!("Year {} Day {:02}: {}", entry.year, entry.day, summary(&error)));
+                }
+            }
+        }
+    } else {
+        let reports = select(args)?.iter()
+            .map(|entry| DayReport { year: entry.year, day: entry.day, outcome: solve_day(entry, &inputs, &parts) })
+            .collect::<Vec<_>>();
+
+        print!("{}", report::render(&reports, args.format)?);
+        failures.extend(reports.iter().filter(|report| report.failed()).map(|report| format!("Year {} Day {:02}", report.year, report.day)));
+
+        for report in &reports {
+            if let DayOutcome::Solved { parts, .. } = &report.outcome {
+                for part in parts {
+                    if let Ok(answer) = &part.answer {
+                        answers.set(report.year, report.day, part.part, answer.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    if args.record {
+        answers.save(&inputs.answers_path())?;
+        // keeps stdout parseable for the structured formats
+        match args.format {
+            Format::Text => println!("Recorded answers in {}", inputs.answers_path().display()),
+            _ => eprintln!("Recorded answers in {}", inputs.answers_path().display()),
+        }
+    }
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    if args.format == Format::Text {
+        println!("\nFailed:");
+        failures.iter().for_each(|failure| println!("{failure}"));
+    }
+
+    Err(anyhow!("{} of the selected days or parts failed", failures.len()))
+}
+
+/// Re-runs every selected day that has a recorded answer and compares the results.
+pub fn verify(args: &RunArgs) -> anyhow::Result<()> {
+    let parts = parts(args);
+    let inputs = InputProvider::new(None, args.input_dir.clone());
+    let answers = Answers::load(&inputs.answers_path())?;
+
+    let entries = registry::all().into_iter()
+        .filter(|entry| args.year.is_none_or(|year| year == entry.year))
+        .filter(|entry| args.day.is_none_or(|day| day == entry.day))
+        .filter(|entry| parts.iter().any(|&part| answers.get(entry.year, entry.day, part).is_some()))
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        anyhow::bail!("No recorded answers to verify in {}", inputs.answers_path().display());
+    }
+
+    let (mut correct, mut failures) = (0, 0);
+
+    for entry in entries {
+        let results = verify_day(&entry, &inputs, &parts, &answers);
+
+        for (part, result) in results {
+            print!("Year {} Day {:02} Part {part}: ", entry.year, entry.day);
+            match result {
+                Ok(()) => {
+                    correct += 1;
+                    println!("ok");
+                }
+                Err(error) => {
+                    failures += 1;
+                    println!("{error}");
+                }
+            }
+        }
+    }
+
+    println!("{correct} correct, {failures} failed");
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(anyhow!("{failures} recorded answers could not be reproduced")),
+    }
+}
+
+fn verify_day(entry: &Entry, inputs: &InputProvider, parts: &[Part], answers: &Answers) -> Vec<(Part, anyhow::Result<()>)> {
+    let recorded = parts.iter()
+        .filter_map(|&part| answers.get(entry.year, entry.day, part).map(|answer| (part, answer)))
+        .collect::<Vec<_>>();
+
+    let day = inputs.load(entry.year, entry.day).map_err(anyhow::Error::from)
+        .and_then(|input| entry.parse(&input));
+
+    let mut day = match day {
+        Ok(day) => day,
+        Err(error) => return recorded.into_iter().map(|(part, _)| (part, Err(anyhow!("{error}")))).collect(),
+    };
+
+    recorded.into_iter().map(|(part, expected)| {
+        day.after_test(part);
+        let result = day.solve(part).and_then(|actual| match actual == expected {
+            true => Ok(()),
+            false => Err(anyhow!("MISMATCH expected {expected:?}, got {actual:?}")),
+        });
+
+        (part, result)
+    }).collect()
+}
+
+pub(crate) fn parts(args: &RunArgs) -> Vec<Part> {
+    match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+pub(crate) fn select(args: &RunArgs) -> anyhow::Result<Vec<Entry>> {
+    let year = match args.year {
+        Some(year) => year,
+        None => registry::latest().ok_or_else(|| anyhow!("No days are registered"))?.year,
+    };
+
+    if args.all {
+        let entries = registry::year(year);
+        if entries.is_empty() {
+            anyhow::bail!("No days registered for year {year}");
+        }
+
+        return Ok(entries);
+    }
+
+    let entry = match args.day {
+        Some(day) => registry::find(year, day),
+        None => registry::year(year).pop(),
+    };
+
+    entry.map(|entry| vec![entry]).ok_or_else(|| match args.day {
+        Some(day) => anyhow!("Year {year} Day {day:02} is not registered"),
+        None => anyhow!("No days registered for year {year}"),
+    })
+}
+
+/// Runs the examples and solutions of a day, a failing example or solution is printed, added to `failures` and
+/// the next part continues.
+fn run_day(entry: &Entry, inputs: &InputProvider, parts: &[Part], failures: &mut Vec<String>) -> anyhow::Result<Vec<(Part, String)>> {
+    let input = inputs.load(entry.year, entry.day)?;
+
+    // running day in year
+    let mut day = entry.parse(&input)?;
+    let mut answers = Vec::new();
+
+    println!("Running Year {} Day {:02}", entry.year, entry.day);
+
+    for &part in parts {
+        if let Err(error) = day.test(part) {
+            println!("Test {part} failed: {error:#}");
+            failures.push(format!("Year {} Day {:02} Part {part}: {}", entry.year, entry.day, summary(&error)));
+            continue;
+        }
+
+        day.after_test(part);
+        println!("Test {part} passed");
+        let time = Instant::now();
+        match day.solve(part) {
+            Ok(answer) => {
+                println!("Solution {part}: {:<20} took ~{}ms", answer, (Instant::now() - time).as_millis());
+                answers.push((part, answer));
+            }
+            Err(error) => {
+                println!("Solution {part} failed: {error:#}");
+                failures.push(format!("Year {} Day {:02} Part {part}: {}", entry.year, entry.day, summary(&error)));
+            }
+        }
+    }
+
+    Ok(answers)
+}
+
+/// First line of an error, the full report has already been printed.
+fn summary(error: &anyhow::Error) -> String {
+    error.to_string().lines().next().unwrap_or_default().to_string()
+}
+
+/// Outcome of a single part of a [`DayReport`].
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub example: Result<(), String>,
+    pub answer: Result<String, String>,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub enum DayOutcome {
+    /// No input file was found, so the day was not run at all.
+    Skipped(String),
+    /// Reading or parsing the input failed, or the day panicked outside of a part.
+    Failed(String),
+    Solved { parse_time: Duration, parts: Vec<PartReport> },
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub outcome: DayOutcome,
+}
+
+impl DayReport {
+    pub fn failed(&self) -> bool {
+        match &self.outcome {
+            DayOutcome::Skipped(_) => false,
+            DayOutcome::Failed(_) => true,
+            DayOutcome::Solved { parts, .. } => parts.iter().any(|part| part.example.is_err() || part.answer.is_err()),
+        }
+    }
+}
+
+/// Runs every selected day concurrently on the rayon thread pool and prints the reports ordered by year and day.
+/// A panicking day is reported as failed while the others keep running.
+pub fn run_parallel(args: &RunArgs) -> anyhow::Result<()> {
+    let inputs = InputProvider::new(None, args.input_dir.clone());
+    let entries = registry::all().into_iter()
+        .filter(|entry| args.year.is_none_or(|year| year == entry.year))
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        anyhow::bail!("No days registered for year {}", args.year.unwrap_or_default());
+    }
+
+    let time = Instant::now();
+    let reports = solve_parallel(&entries, &inputs, &parts(args));
+    let wall_time = time.elapsed();
+    let failed = reports.iter().filter(|report| report.failed()).count();
+
+    if args.format == Format::Text {
+        for report in &reports {
+            print_report(report);
+        }
+
+        let skipped = reports.iter().filter(|report| matches!(report.outcome, DayOutcome::Skipped(_))).count();
+        println!(
+            "{} days run, {failed} failed, {skipped} skipped without input, took ~{}ms",
+            reports.len() - skipped, wall_time.as_millis()
+        );
+    } else {
+        print!("{}", report::render(&reports, args.format)?);
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(anyhow!("{failed} days failed")),
+    }
+}
+
+pub fn solve_parallel(entries: &[Entry], inputs: &InputProvider, parts: &[Part]) -> Vec<DayReport> {
+    // the reports carry the panic messages, the default hook would only interleave them on stderr
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let reports = entries.par_iter()
+        .map(|entry| DayReport { year: entry.year, day: entry.day, outcome: solve_day(entry, inputs, parts) })
+        .collect();
+
+    panic::set_hook(hook);
+    reports
+}
+
+fn solve_day(entry: &Entry, inputs: &InputProvider, parts: &[Part]) -> DayOutcome {
+    let input = match inputs.load(entry.year, entry.day) {
+        Ok(input) => input,
+        Err(error @ input::Error::Missing { .. }) => return DayOutcome::Skipped(error.to_string()),
+        Err(error) => return DayOutcome::Failed(error.to_string()),
+    };
+
+    let time = Instant::now();
+    let mut day = match catch(|| entry.parse(&input)) {
+        Ok(day) => day,
+        Err(error) => return DayOutcome::Failed(error),
+    };
+    let parse_time = time.elapsed();
+
+    let parts = parts.iter().map(|&part| {
+        let example = catch(|| day.test(part));
+        let time = Instant::now();
+        let answer = catch(|| {
+            day.after_test(part);
+            day.solve(part)
+        });
+
+        PartReport { part, example, answer, time: time.elapsed() }
+    }).collect();
+
+    DayOutcome::Solved { parse_time, parts }
+}
+
+/// Runs `f`, turning both errors and panics into a message.
+fn catch<T>(f: impl FnOnce() -> anyhow::Result<T>) -> Result<T, String> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|error| format!("{error:#}")),
+        Err(payload) => Err(match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+            (Some(message), _) => format!("panicked: {message}"),
+            (_, Some(message)) => format!("panicked: {message}"),
+            _ => String::from("panicked"),
+        }),
+    }
+}
+
+fn print_report(report: &DayReport) {
+    match &report.outcome {
+        DayOutcome::Skipped(_) => {}
+        DayOutcome::Failed(error) => println!("Year {} Day {:02} failed: {error}", report.year, report.day),
+        DayOutcome::Solved { parse_time, parts } => {
+            println!("Year {} Day {:02} parsed in ~{:.3}ms", report.year, report.day, parse_time.as_secs_f64() * 1000.0);
+
+            for part in parts {
+                if let Err(error) = &part.example {
+                    println!("Test {} failed: {error}", part.part);
+                }
+
+                match &part.answer {
+                    Ok(answer) => println!("Solution {}: {:<20} took ~{:.3}ms", part.part, answer, part.time.as_secs_f64() * 1000.0),
+                    Err(error) => println!("Solution {} failed: {error}", part.part),
+                }
+            }
+        }
+    }
+}
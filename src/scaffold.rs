@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::bail;
+
+use crate::cli::NewDayArgs;
+use crate::registry;
+
+/// Output types offered by `new-day --output`.
+pub const OUTPUT_TYPES: [&str; 9] = ["usize", "u32", "u64", "u128", "i32", "i64", "i128", "isize", "String"];
+
+/// Creates `src/yearYYYY/dayNN/mod.rs` from a template. `build.rs` picks up the new directory, so the day is
+/// declared and registered without further edits. Existing days are never overwritten.
+pub fn new_day(args: &NewDayArgs) -> anyhow::Result<()> {
+    let (year, day) = (args.year, args.day);
+    let day_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("year{year}")).join(format!("day{day:02}"));
+
+    if day_dir.exists() || registry::find(year, day).is_some() {
+        bail!("Year {year} Day {day:02} already exists, not overwriting {}", day_dir.display());
+    }
+
+    fs::create_dir_all(&day_dir)?;
+    fs::write(day_dir.join("mod.rs"), template(&args.output))?;
+
+    println!("Created {}", day_dir.join("mod.rs").display());
+    println!("Put the input into inputs/{year}/{day:02}.txt and run it with `cargo run -- run --year {year} --day {day}`");
+
+    Ok(())
+}
+
+fn template(output: &str) -> String {
+    format!(r#"use std::str::FromStr;
+
+#[derive(Default, Clone, Debug)]
+pub struct Day {{
+    #[allow(dead_code)]
+    lines: Vec<String>,
+}}
+
+impl FromStr for Day {{
+    type Err = crate::aoc::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        Ok(Self {{
+            lines: s.lines().map(str::to_string).collect(),
+        }})
+    }}
+}}
+
+impl crate::aoc::Day for Day {{
+    type Output = {output};
+
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {{
+        vec![]
+    }}
+
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {{
+        vec![]
+    }}
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {{
+        Err(crate::aoc::Error::NoSolutionFound.into())
+    }}
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {{
+        Err(crate::aoc::Error::NoSolutionFound.into())
+    }}
+}}
+"#)
+}
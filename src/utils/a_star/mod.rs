@@ -1,59 +1,69 @@
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::utils::search::Cost;
 
 #[derive(Eq, PartialEq, Debug)]
-struct Node<T> {
+struct Node<T, C> {
     state: T,
-    f_score: i32,
-    g_score: i32,
+    f_score: C,
+    g_score: C,
 }
 
-impl<T: Eq + PartialEq> Ord for Node<T> {
+impl<T: Eq + PartialEq, C: Ord> Ord for Node<T, C> {
     fn cmp(&self, other: &Self) -> Ordering {
         other.f_score.cmp(&self.f_score)
     }
 }
 
-impl<T: Eq + PartialEq> PartialOrd for Node<T> {
+impl<T: Eq + PartialEq, C: Ord> PartialOrd for Node<T, C> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-/// A\* algorithm implementation to find the shortest path from a start state to a goal state.
+/// A\* algorithm implementation to find the shortest path from a start state to any state matching a goal predicate.
 ///
 /// # Parameters
 /// - `start`: The initial state from which the search begins.
-/// - `goal`: The target state that the search aims to reach.
-/// - `h`: A heuristic function that estimates the cost from a given state to the goal state.
+/// - `is_goal`: Decides whether a state is a goal, e.g. `|state| state.position == end` for any direction at `end`.
+/// - `h`: A heuristic function that estimates the cost from a given state to the closest goal.
 /// - `g`: A function that calculates the cost to move from one state to another.
 /// - `f`: A function that combines the g\_cost and h\_cost to produce the f\_score.
 /// - `neighbour`: A function that generates the neighboring states from a given state.
 ///
+/// Costs can be any [`Cost`], e.g. `i32`, `usize` or `u64`.
+///
 /// # Returns
 /// An `Option` containing a tuple with a vector of states representing the path from start to goal and the total cost of that path.
 /// Returns `None` if no path is found.
-pub fn a_star<State, F, G, H, Neighbour>(start: State, goal: State, h: H, g: G, f: F, neighbour: Neighbour) -> Option<(Vec<State>, i32)>
+pub fn a_star<State, C, Goal, F, G, H, Neighbour>(start: State, is_goal: Goal, h: H, g: G, f: F, neighbour: Neighbour) -> Option<(Vec<State>, C)>
 where
     State: Clone + Eq + PartialEq + std::hash::Hash,
-    F: Fn(&State, i32, i32) -> i32,
-    G: Fn(&State, &State) -> i32,
-    H: Fn(&State, &State) -> i32,
+    C: Cost,
+    Goal: Fn(&State) -> bool,
+    F: Fn(&State, C, C) -> C,
+    G: Fn(&State, &State) -> C,
+    H: Fn(&State) -> C,
     Neighbour: Fn(&State) -> Vec<State>,
 {
     let mut open_set = BinaryHeap::new();
     let mut came_from: HashMap<State, State> = HashMap::new();
     let mut g_scores = HashMap::new();
 
-    g_scores.insert(start.clone(), 0);
+    g_scores.insert(start.clone(), C::zero());
     open_set.push(Node {
-        state: start.clone(),
-        f_score: f(&start, 0, h(&start, &goal)),
-        g_score: 0,
+        f_score: f(&start, C::zero(), h(&start)),
+        state: start,
+        g_score: C::zero(),
     });
 
     while let Some(current) = open_set.pop() {
-        if current.state == goal {
+        if current.g_score > g_scores[&current.state] {
+            continue;
+        }
+
+        if is_goal(&current.state) {
             let mut path = vec![current.state.clone()];
             let mut current_state = &current.state;
             while let Some(prev_state) = came_from.get(current_state) {
@@ -65,15 +75,14 @@ where
         }
 
         for neighbor in neighbour(&current.state) {
-            let tentative_g_score = g_scores[&current.state] + g(&current.state, &neighbor);
+            let tentative_g_score = current.g_score + g(&current.state, &neighbor);
 
-            if tentative_g_score < *g_scores.get(&neighbor).unwrap_or(&i32::MAX) {
+            if g_scores.get(&neighbor).is_none_or(|known| tentative_g_score < *known) {
                 came_from.insert(neighbor.clone(), current.state.clone());
                 g_scores.insert(neighbor.clone(), tentative_g_score);
-                let h_score = h(&neighbor, &goal);
                 open_set.push(Node {
-                    state: neighbor.clone(),
-                    f_score: f(&neighbor, tentative_g_score, h_score),
+                    f_score: f(&neighbor, tentative_g_score, h(&neighbor)),
+                    state: neighbor,
                     g_score: tentative_g_score,
                 });
             }
@@ -83,79 +92,213 @@ where
     None
 }
 
-/// A\* algorithm implementation to find all the shortest path from a start state to a goal state.
+/// Every shortest path found by [`a_star_all_paths`], stored as a DAG of predecessors instead of one `Vec` per path, which
+/// would grow exponentially on open mazes.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<State, C> {
+    /// Cost of every shortest path.
+    pub cost: C,
+    /// Goal states reached with `cost`, a goal predicate can match several.
+    pub goals: Vec<State>,
+    /// For every state on a shortest path, the states it is reached from with its lowest cost. The start has none.
+    pub predecessors: HashMap<State, Vec<State>>,
+}
+
+impl<State: Clone + Eq + std::hash::Hash, C> ShortestPaths<State, C> {
+    /// Number of distinct shortest paths, counted in linear time over the DAG.
+    pub fn count_paths(&self) -> usize {
+        let mut counts: HashMap<&State, usize> = HashMap::new();
+        let mut stack = self.goals.iter().collect::<Vec<_>>();
+
+        while let Some(&state) = stack.last() {
+            if counts.contains_key(state) {
+                stack.pop();
+                continue;
+            }
+
+            let predecessors = self.predecessors_of(state);
+            let missing = predecessors.iter().filter(|pred| !counts.contains_key(pred)).collect::<Vec<_>>();
+
+            if missing.is_empty() {
+                let count = match predecessors.is_empty() {
+                    true => 1,
+                    false => predecessors.iter().map(|pred| counts[pred]).sum(),
+                };
+                counts.insert(state, count);
+                stack.pop();
+            } else {
+                stack.extend(missing);
+            }
+        }
+
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+
+    /// Every state that lies on at least one shortest path.
+    pub fn nodes(&self) -> HashSet<State> {
+        let mut nodes = HashSet::new();
+        let mut stack = self.goals.iter().collect::<Vec<_>>();
+
+        while let Some(state) = stack.pop() {
+            if nodes.insert(state.clone()) {
+                stack.extend(self.predecessors_of(state));
+            }
+        }
+
+        nodes
+    }
+
+    /// Lazily walks the DAG and yields every shortest path from start to goal, one at a time.
+    pub fn paths(&self) -> Paths<'_, State, C> {
+        Paths { shortest_paths: self, next_goal: 0, stack: Vec::new() }
+    }
+
+    fn predecessors_of(&self, state: &State) -> &[State] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Iterator returned by [`ShortestPaths::paths`].
+pub struct Paths<'a, State, C> {
+    shortest_paths: &'a ShortestPaths<State, C>,
+    next_goal: usize,
+    /// Current path from the goal backwards, each state with the index of the next predecessor to try.
+    stack: Vec<(&'a State, usize)>,
+}
+
+impl<State: Clone + Eq + std::hash::Hash, C> Iterator for Paths<'_, State, C> {
+    type Item = Vec<State>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((state, index)) = self.stack.last_mut() else {
+                let goal = self.shortest_paths.goals.get(self.next_goal)?;
+                self.next_goal += 1;
+                self.stack.push((goal, 0));
+                continue;
+            };
+
+            let predecessors = self.shortest_paths.predecessors_of(state);
+
+            if predecessors.is_empty() && *index == 0 {
+                *index = 1;
+                return Some(self.stack.iter().rev().map(|(state, _)| (*state).clone()).collect());
+            } else if let Some(predecessor) = predecessors.get(*index) {
+                *index += 1;
+                self.stack.push((predecessor, 0));
+            } else {
+                self.stack.pop();
+            }
+        }
+    }
+}
+
+/// A\* algorithm implementation to find all the shortest paths from a start state to any state matching a goal predicate.
 ///
 /// # Parameters
 /// - `start`: The initial state from which the search begins.
-/// - `goal`: The target state that the search aims to reach.
-/// - `h`: A heuristic function that estimates the cost from a given state to the goal state.
+/// - `is_goal`: Decides whether a state is a goal, e.g. `|state| state.position == end` for any direction at `end`.
+/// - `h`: A heuristic function that estimates the cost from a given state to the closest goal, it must not overestimate.
 /// - `g`: A function that calculates the cost to move from one state to another.
 /// - `f`: A function that combines the g\_cost and h\_cost to produce the f\_score.
 /// - `neighbour`: A function that generates the neighboring states from a given state.
 ///
+/// Costs can be any [`Cost`], e.g. `i32`, `usize` or `u64`. Zero-cost edges are allowed, a predecessor reached through
+/// one is only skipped if it would close a cycle, e.g. a free self loop, so the predecessors always form a DAG.
+///
 /// # Returns
-/// An `Option` containing a tuple with a vector of states representing the path from start to goal and the total cost of that path.
-/// Returns `None` if no path is found.
-pub fn a_star_all_paths<State, F, G, H, Neighbour>(start: State, goal: State, h: H, g: G, f: F, neighbour: Neighbour) -> Option<(Vec<Vec<State>>, i32)>
+/// The [`ShortestPaths`] DAG of every path with the lowest cost to any goal, or `None` if no path is found.
+pub fn a_star_all_paths<State, C, Goal, F, G, H, Neighbour>(start: State, is_goal: Goal, h: H, g: G, f: F, neighbour: Neighbour) -> Option<ShortestPaths<State, C>>
 where
     State: Clone + Eq + PartialEq + std::hash::Hash,
-    F: Fn(&State, i32, i32) -> i32,
-    G: Fn(&State, &State) -> i32,
-    H: Fn(&State, &State) -> i32,
+    C: Cost,
+    Goal: Fn(&State) -> bool,
+    F: Fn(&State, C, C) -> C,
+    G: Fn(&State, &State) -> C,
+    H: Fn(&State) -> C,
     Neighbour: Fn(&State) -> Vec<State>,
 {
     let mut open_set = BinaryHeap::new();
     let mut came_from: HashMap<State, Vec<State>> = HashMap::new();
     let mut g_scores = HashMap::new();
+    let mut best: Option<(C, Vec<State>)> = None;
 
-    g_scores.insert(start.clone(), 0);
+    g_scores.insert(start.clone(), C::zero());
     open_set.push(Node {
-        state: start.clone(),
-        f_score: f(&start, 0, h(&start, &goal)),
-        g_score: 0,
+        f_score: f(&start, C::zero(), h(&start)),
+        state: start,
+        g_score: C::zero(),
     });
 
     while let Some(current) = open_set.pop() {
-        if current.state == goal {
-            let mut all_paths = Vec::new();
-            let mut stack = vec![(vec![current.state.clone()], current.state.clone())];
-
-            // Rekonstruiere alle Pfade
-            while let Some((mut path, state)) = stack.pop() {
-                if let Some(predecessors) = came_from.get(&state) {
-                    for pred in predecessors {
-                        let mut new_path = path.clone();
-                        new_path.push(pred.clone());
-                        stack.push((new_path, pred.clone()));
-                    }
-                } else {
-                    path.reverse();
-                    all_paths.push(path);
-                }
-            }
+        // keep expanding everything that could still lie on a shortest path, so predecessors with the same f_score
+        // as a goal are not missed
+        if best.as_ref().is_some_and(|(cost, _)| current.f_score > *cost) {
+            break;
+        }
+
+        if current.g_score > g_scores[&current.state] {
+            continue;
+        }
 
-            return Some((all_paths, current.g_score));
+        if is_goal(&current.state) {
+            match &mut best {
+                Some((cost, goals)) if current.g_score == *cost => goals.push(current.state.clone()),
+                Some(_) => {}
+                None => best = Some((current.g_score, vec![current.state.clone()])),
+            }
+            continue;
         }
 
         for neighbor in neighbour(&current.state) {
-            let tentative_g_score = g_scores[&current.state] + g(&current.state, &neighbor);
+            let cost = g(&current.state, &neighbor);
+            let tentative_g_score = current.g_score + cost;
 
-            if tentative_g_score < *g_scores.get(&neighbor).unwrap_or(&i32::MAX) {
-                came_from.insert(neighbor.clone(), vec![current.state.clone()]);
-                g_scores.insert(neighbor.clone(), tentative_g_score);
-                let h_score = h(&neighbor, &goal);
-                open_set.push(Node {
-                    state: neighbor.clone(),
-                    f_score: f(&neighbor, tentative_g_score, h_score),
-                    g_score: tentative_g_score,
-                });
-            } else if tentative_g_score == *g_scores.get(&neighbor).unwrap_or(&i32::MAX) {
-                if let Some(pred_list) = came_from.get_mut(&neighbor) {
-                    pred_list.push(current.state.clone());
+            match g_scores.get(&neighbor) {
+                Some(known) if tentative_g_score > *known => {}
+                // only a zero-cost edge can lead back to an ancestor at the same cost, that would close a cycle
+                Some(known) if tentative_g_score == *known && cost.is_zero() && is_ancestor(&came_from, &current.state, &neighbor) => {}
+                Some(known) if tentative_g_score == *known => {
+                    if let Some(pred_list) = came_from.get_mut(&neighbor) {
+                        pred_list.push(current.state.clone());
+                    }
+                }
+                _ => {
+                    came_from.insert(neighbor.clone(), vec![current.state.clone()]);
+                    g_scores.insert(neighbor.clone(), tentative_g_score);
+                    open_set.push(Node {
+                        f_score: f(&neighbor, tentative_g_score, h(&neighbor)),
+                        state: neighbor,
+                        g_score: tentative_g_score,
+                    });
                 }
             }
         }
     }
 
-    None
-}
\ No newline at end of file
+    let (cost, goals) = best?;
+    let mut shortest_paths = ShortestPaths { cost, goals, predecessors: came_from };
+    // only keep the part of the search that leads to a goal
+    let nodes = shortest_paths.nodes();
+    shortest_paths.predecessors.retain(|state, _| nodes.contains(state));
+
+    Some(shortest_paths)
+}
+
+/// Whether `ancestor` is `state` itself or reachable from it by following `predecessors`.
+fn is_ancestor<State: Eq + std::hash::Hash>(predecessors: &HashMap<State, Vec<State>>, state: &State, ancestor: &State) -> bool {
+    let mut visited = HashSet::new();
+    let mut stack = vec![state];
+
+    while let Some(state) = stack.pop() {
+        if state == ancestor {
+            return true;
+        }
+
+        if visited.insert(state) {
+            stack.extend(predecessors.get(state).into_iter().flatten());
+        }
+    }
+
+    false
+}
@@ -1,5 +1,5 @@
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, Index, IndexMut};
+use std::ops::{Add, Index, IndexMut, Mul, Neg};
 use std::slice::Iter;
 use thiserror::Error;
 
@@ -12,35 +12,26 @@ pub struct Grid<T> {
 
 impl<T> Grid<T> {
     pub fn adjacent_coords_8(&self, x: usize, y: usize) -> Vec<Coord> {
-        let mut coords = Vec::new();
-
-        for dy in -1isize..=1 {
-            for dx in -1isize..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-
-                let new_x = x as isize + dx;
-                let new_y = y as isize + dy;
-
-                if self.in_bounds(new_x, new_y) {
-                    coords.push((new_x as usize, new_y as usize));
-                }
-            }
-        }
-
-        coords
+        self.neighbours_8((x, y)).map(|(coord, _)| coord).collect()
     }
 }
 
 #[derive(Debug, Error)]
 pub enum Error {
     OutOfBounds(Coord),
+    /// Row `row` (1-based) has `found` columns while the rows before it have `expected`.
+    RaggedRow { row: usize, expected: usize, found: usize },
+    /// `len` cells cannot fill a `width` x `height` grid.
+    SizeMismatch { width: usize, height: usize, len: usize },
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Out of bounds: {:?}", self)
+        match self {
+            Error::OutOfBounds(_) => write!(f, "Out of bounds: {:?}", self),
+            Error::RaggedRow { row, expected, found } => write!(f, "Row {row} has {found} columns, expected {expected}"),
+            Error::SizeMismatch { width, height, len } => write!(f, "{len} cells do not fill a {width}x{height} grid"),
+        }
     }
 }
 
@@ -164,12 +155,9 @@ impl<T: Clone> Grid<T> {
                     visited[y][x] = true;
                     current_group.push((x, y));
 
-                    for (dx, dy) in &[(0, 1), (1, 0), (0, -1), (-1, 0)] {
-                        let new_x = x as isize + dx;
-                        let new_y = y as isize + dy;
-
-                        if self.in_bounds(new_x, new_y) && !visited[new_y as usize][new_x as usize] && is_adjacent(&current_segment_value, &self[(new_x as usize, new_y as usize)]) {
-                            stack.push((new_x as usize, new_y as usize));
+                    for ((new_x, new_y), value) in self.neighbours_4((x, y)) {
+                        if !visited[new_y][new_x] && is_adjacent(&current_segment_value, value) {
+                            stack.push((new_x, new_y));
                         }
                     }
                 }
@@ -401,23 +389,230 @@ impl<T> Grid<T> {
         }
     }
 
-    pub fn from_raw_input(input: &str) -> Grid<T> where T: From<char> {
-        Grid::<T>::new(
-            input.lines().collect::<Vec<_>>()[0].len(),
-            input.lines().count(),
-            input.lines().flat_map(|line| line.chars()).map(|char| T::from(char)).collect(),
-        )
+    /// [`Grid::new`] that checks `data` fills exactly `width` x `height` cells.
+    pub fn try_new(width: usize, height: usize, data: Vec<T>) -> Result<Self, Error> {
+        if width * height != data.len() {
+            return Err(Error::SizeMismatch { width, height, len: data.len() });
+        }
+
+        Ok(Self::new(width, height, data))
+    }
+
+    /// Builds a grid from rows that all have to be as wide as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+        let width = rows.first().map_or(0, Vec::len);
+
+        if let Some((row, found)) = rows.iter().map(Vec::len).enumerate().find(|(_, len)| *len != width) {
+            return Err(Error::RaggedRow { row: row + 1, expected: width, found });
+        }
+
+        let height = rows.len();
+        Ok(Self::new(width, height, rows.into_iter().flatten().collect()))
+    }
+
+    /// Like [`Grid::from_rows`], but rows shorter than the widest one are padded with `fill` instead of rejected.
+    pub fn from_rows_padded(rows: Vec<Vec<T>>, fill: T) -> Grid<T> where T: Clone {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+
+        let data = rows.into_iter().flat_map(|mut row| {
+            row.resize(width, fill.clone());
+            row
+        }).collect();
+
+        Self::new(width, height, data)
+    }
+
+    /// Maps every character of `input` with `cell`, one row per line. `\r\n` line endings and trailing empty lines
+    /// are ignored, rows of differing width are an [`Error::RaggedRow`].
+    pub fn parse(input: &str, cell: impl Fn(char) -> T) -> Result<Grid<T>, Error> {
+        Self::from_rows(lines(input).map(|line| line.chars().map(&cell).collect()).collect())
+    }
+
+    /// [`Grid::parse`] that pads short rows with `fill`.
+    pub fn parse_padded(input: &str, cell: impl Fn(char) -> T, fill: T) -> Grid<T> where T: Clone {
+        Self::from_rows_padded(lines(input).map(|line| line.chars().map(&cell).collect()).collect(), fill)
+    }
+
+    pub fn from_raw_input(input: &str) -> Result<Grid<T>, Error> where T: From<char> {
+        Self::parse(input, T::from)
     }
 }
 
 impl Grid<u8> {
-    pub fn parse_to_u8(input: &str) -> Grid<u8> {
-        let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
+    pub fn parse_to_u8(input: &str) -> Result<Grid<u8>, Error> {
+        Self::from_rows(lines(input).map(|line| line.as_bytes().to_vec()).collect())
+    }
+}
+
+/// Lines of a grid, without `\r` and without the empty lines a trailing newline leaves behind.
+fn lines(input: &str) -> impl Iterator<Item = &str> {
+    let lines = input.lines().map(|line| line.trim_end_matches('\r')).collect::<Vec<_>>();
+    let height = lines.iter().rposition(|line| !line.is_empty()).map_or(0, |last| last + 1);
+
+    lines.into_iter().take(height)
+}
+
+/// One of the eight compass directions. `y` grows downwards, so [`Direction::North`] is `(0, -1)`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// North, East, South and West, clockwise.
+    pub const CARDINAL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+    /// All eight directions, clockwise starting at North.
+    pub const ALL: [Direction; 8] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest,
+    ];
+
+    pub fn offset(self) -> Offset {
+        match self {
+            Direction::North => Offset::new(0, -1),
+            Direction::NorthEast => Offset::new(1, -1),
+            Direction::East => Offset::new(1, 0),
+            Direction::SouthEast => Offset::new(1, 1),
+            Direction::South => Offset::new(0, 1),
+            Direction::SouthWest => Offset::new(-1, 1),
+            Direction::West => Offset::new(-1, 0),
+            Direction::NorthWest => Offset::new(-1, -1),
+        }
+    }
+
+    /// Rotates clockwise in steps of 45°, negative steps rotate counterclockwise.
+    pub fn rotate(self, eighths: i32) -> Direction {
+        Direction::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
 
-        let width = raw[0].len();
-        let height = raw.len();
-        let data= raw.concat();
+    pub fn is_cardinal(self) -> bool {
+        (self as u8).is_multiple_of(2)
+    }
+}
+
+/// Relative movement on a grid.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Offset {
+    pub dx: isize,
+    pub dy: isize,
+}
+
+impl Offset {
+    pub const fn new(dx: isize, dy: isize) -> Self {
+        Self { dx, dy }
+    }
+
+    /// Rotates by 90° clockwise.
+    pub fn turn_right(self) -> Offset {
+        Offset::new(-self.dy, self.dx)
+    }
+
+    /// Rotates by 90° counterclockwise.
+    pub fn turn_left(self) -> Offset {
+        Offset::new(self.dy, -self.dx)
+    }
+}
+
+impl From<Direction> for Offset {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Offset::new(self.dx + rhs.dx, self.dy + rhs.dy)
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Offset::new(self.dx * rhs, self.dy * rhs)
+    }
+}
 
-        Grid { width, height, data }
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Self::Output {
+        Offset::new(-self.dx, -self.dy)
+    }
+}
+
+/// Moves `coord` by `offset` on a `width` x `height` torus, leaving one side enters the other.
+pub fn step_wrapping(coord: Coord, offset: impl Into<Offset>, width: usize, height: usize) -> Coord {
+    let offset = offset.into();
+
+    (
+        (coord.0 as isize + offset.dx).rem_euclid(width as isize) as usize,
+        (coord.1 as isize + offset.dy).rem_euclid(height as isize) as usize,
+    )
+}
+
+impl<T> Grid<T> {
+    /// `coord` moved by `offset`, or `None` when that leaves the grid.
+    pub fn step(&self, coord: Coord, offset: impl Into<Offset>) -> Option<Coord> {
+        let offset = offset.into();
+        let x = coord.0.checked_add_signed(offset.dx).filter(|x| *x < self.width)?;
+        let y = coord.1.checked_add_signed(offset.dy).filter(|y| *y < self.height)?;
+
+        Some((x, y))
     }
-}
\ No newline at end of file
+
+    /// `coord` moved by `offset`, wrapping around the edges of the grid.
+    pub fn step_wrapping(&self, coord: Coord, offset: impl Into<Offset>) -> Coord {
+        step_wrapping(coord, offset, self.width, self.height)
+    }
+
+    /// The up to four orthogonal neighbours of `coord` with their values.
+    pub fn neighbours_4(&self, coord: Coord) -> Neighbours<'_, T> {
+        Neighbours { grid: self, coord, directions: Direction::CARDINAL.iter() }
+    }
+
+    /// The up to eight neighbours of `coord`, diagonals included, with their values.
+    pub fn neighbours_8(&self, coord: Coord) -> Neighbours<'_, T> {
+        Neighbours { grid: self, coord, directions: Direction::ALL.iter() }
+    }
+}
+
+/// Iterator over the neighbours of a cell that lie inside the grid, see [`Grid::neighbours_4`].
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    coord: Coord,
+    directions: Iter<'static, Direction>,
+}
+
+impl<'a, T> Iterator for Neighbours<'a, T> {
+    type Item = (Coord, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.directions.by_ref().find_map(|direction| {
+            let coord = self.grid.step(self.coord, *direction)?;
+            Some((coord, &self.grid[coord]))
+        })
+    }
+}
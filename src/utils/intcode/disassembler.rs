@@ -0,0 +1,120 @@
+use std::fmt::{Display, Formatter};
+
+use crate::utils::intcode::{Error, IntCodeComputer, OptCode, ParameterMode};
+
+/// A decoded instruction, rendered as assembly like `ADD [12], #5 -> [20]`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Instruction {
+    pub address: usize,
+    pub opt_code: OptCode,
+    pub parameters: Vec<Parameter>,
+}
+
+/// A raw parameter together with the mode it is read in.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Parameter {
+    pub mode: ParameterMode,
+    pub value: i64,
+}
+
+impl Instruction {
+    /// Decodes the instruction at `address`, memory beyond the end of `memory` reads as 0.
+    pub fn decode(memory: &[i64], address: usize) -> Result<Instruction, Error> {
+        let read = |address: usize| memory.get(address).copied().unwrap_or(0);
+        let (opt_code, parameter_modes) = IntCodeComputer::decode_instruction(address, read(address))?;
+
+        let parameters = (0..opt_code.parameters())
+            .map(|index| Parameter { mode: parameter_modes[index], value: read(address + index + 1) })
+            .collect();
+
+        Ok(Instruction { address, opt_code, parameters })
+    }
+
+    /// Number of memory cells taken by the opcode and its parameters.
+    pub fn size(&self) -> usize {
+        self.parameters.len() + 1
+    }
+
+    /// The parameter written to, for instructions that store a result.
+    pub fn destination(&self) -> Option<Parameter> {
+        self.opt_code.writes().then(|| *self.parameters.last().expect("writing instructions have parameters"))
+    }
+
+    /// The parameters that are read.
+    pub fn sources(&self) -> &[Parameter] {
+        match self.opt_code.writes() {
+            true => &self.parameters[..self.parameters.len() - 1],
+            false => &self.parameters,
+        }
+    }
+}
+
+fn mnemonic(opt_code: OptCode) -> &'static str {
+    match opt_code {
+        OptCode::Add => "ADD",
+        OptCode::Mul => "MUL",
+        OptCode::In => "IN",
+        OptCode::Out => "OUT",
+        OptCode::JumpIfTrue => "JT",
+        OptCode::JumpIfFalse => "JF",
+        OptCode::LessThan => "LT",
+        OptCode::Equals => "EQ",
+        OptCode::AdjustRelativeBase => "ARB",
+        OptCode::Halt => "HALT",
+    }
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.mode {
+            ParameterMode::Position => write!(f, "[{}]", self.value),
+            ParameterMode::Immediate => write!(f, "#{}", self.value),
+            ParameterMode::Relative if self.value < 0 => write!(f, "[rb{}]", self.value),
+            ParameterMode::Relative => write!(f, "[rb+{}]", self.value),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", mnemonic(self.opt_code))?;
+
+        for (index, parameter) in self.sources().iter().enumerate() {
+            write!(f, "{}{parameter}", if index == 0 { " " } else { ", " })?;
+        }
+
+        if let Some(destination) = self.destination() {
+            write!(f, " -> {destination}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders `program` as one instruction per line, with its address and raw values:
+///
+/// ```text
+///     0: 1001,12,5,20       ADD [12], #5 -> [20]
+///     4: 99                 HALT
+///     5: 42                 DATA 42
+/// ```
+///
+/// The program is decoded from start to end, values that are no valid instruction or whose parameters would run past
+/// the end of the program are shown as `DATA`.
+pub fn disassemble(program: &[i64]) -> String {
+    let mut lines = Vec::new();
+    let mut address = 0;
+
+    while address < program.len() {
+        let (length, assembly) = match Instruction::decode(program, address) {
+            Ok(instruction) if address + instruction.size() <= program.len() => (instruction.size(), instruction.to_string()),
+            _ => (1, format!("DATA {}", program[address])),
+        };
+
+        let raw = program[address..address + length].iter().map(i64::to_string).collect::<Vec<_>>().join(",");
+        lines.push(format!("{address:>5}: {raw:<18} {assembly}"));
+        address += length;
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,440 @@
+//! The IntCode computer of Advent of Code 2019, shared by every day that runs an IntCode program.
+
+use std::collections::VecDeque;
+
+use rayon::prelude::*;
+use thiserror::Error;
+
+use crate::utils::intcode::disassembler::Instruction;
+use crate::utils::intcode::tracer::{Event, Tracer};
+use crate::utils::parse;
+
+pub mod disassembler;
+pub mod network;
+pub mod tracer;
+
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("unknown opcode {value} at address {address}")]
+    UnknownOpcode { address: usize, value: i64 },
+    #[error("unknown parameter mode {value} at address {address}")]
+    UnknownParameterMode { address: usize, value: i64 },
+    #[error("instruction at address {address} writes to an immediate parameter")]
+    ImmediateDestination { address: usize },
+    #[error("instruction at address {address} accesses negative address {value}")]
+    NegativeAddress { address: usize, value: i64 },
+    #[error("step limit of {limit} instructions reached at address {address}")]
+    StepLimit { address: usize, limit: u64 },
+    #[error("machine {machine}: {error}")]
+    InMachine { machine: usize, error: Box<Error> },
+}
+
+/// Why [`IntCodeComputer::run_until_io`] stopped.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Status {
+    /// The program wants to read, but the input queue is empty.
+    NeedsInput,
+    /// The program produced a value, it is not added to the output queue.
+    Output(i64),
+    Halted,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OptCode {
+    Add = 1,
+    Mul = 2,
+    In = 3,
+    Out = 4,
+    JumpIfTrue = 5,
+    JumpIfFalse = 6,
+    LessThan = 7,
+    Equals = 8,
+    AdjustRelativeBase = 9,
+    Halt = 99
+}
+
+impl OptCode {
+    /// Number of parameters following the opcode.
+    pub fn parameters(self) -> usize {
+        match self {
+            OptCode::Add | OptCode::Mul | OptCode::LessThan | OptCode::Equals => 3,
+            OptCode::JumpIfTrue | OptCode::JumpIfFalse => 2,
+            OptCode::In | OptCode::Out | OptCode::AdjustRelativeBase => 1,
+            OptCode::Halt => 0,
+        }
+    }
+
+    /// Whether the last parameter is an address the result is written to.
+    pub fn writes(self) -> bool {
+        matches!(self, OptCode::Add | OptCode::Mul | OptCode::In | OptCode::LessThan | OptCode::Equals)
+    }
+}
+
+impl TryFrom<i64> for OptCode {
+    type Error = i64;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Ok(match value {
+            1 => OptCode::Add,
+            2 => OptCode::Mul,
+            3 => OptCode::In,
+            4 => OptCode::Out,
+            5 => OptCode::JumpIfTrue,
+            6 => OptCode::JumpIfFalse,
+            7 => OptCode::LessThan,
+            8 => OptCode::Equals,
+            9 => OptCode::AdjustRelativeBase,
+            99 => OptCode::Halt,
+            value => return Err(value)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ParameterMode {
+    Position,
+    Immediate,
+    Relative
+}
+
+impl TryFrom<i64> for ParameterMode {
+    type Error = i64;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => ParameterMode::Position,
+            1 => ParameterMode::Immediate,
+            2 => ParameterMode::Relative,
+            value => return Err(value)
+        })
+    }
+}
+
+/// Runs `program` on a fresh computer for every candidate in parallel, prepared by `setup`, and returns any candidate
+/// for which `is_target` holds once the program stopped, e.g. the noun and verb producing `19690720` in 2019 Day 02.
+/// A candidate that makes the program fault is not a match, so `None` means no candidate ran to the target.
+pub fn search_inputs<C, Setup, Target>(program: &[i64], candidates: impl IntoParallelIterator<Item = C>, setup: Setup, is_target: Target) -> Option<C>
+where
+    C: Send,
+    Setup: Fn(&mut IntCodeComputer, &C) + Sync,
+    Target: Fn(&mut IntCodeComputer) -> bool + Sync,
+{
+    candidates.into_par_iter()
+        .find_any(|candidate| {
+            let mut computer = IntCodeComputer::new(program.to_vec());
+            setup(&mut computer, candidate);
+
+            computer.run().is_ok() && is_target(&mut computer)
+        })
+}
+
+/// Parses a comma separated IntCode program.
+pub fn parse_program(input: &str) -> Result<Vec<i64>, crate::aoc::Error> {
+    parse::list(input.trim(), ',')
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct IntCodeComputer {
+    memory: Vec<i64>,
+    instruction_pointer: usize,
+    relative_base: i64,
+    halted: bool,
+    input: VecDeque<i64>,
+    output: VecDeque<i64>,
+    tracer: Option<Tracer>,
+    executed: u64,
+    step_limit: Option<u64>,
+}
+
+impl IntCodeComputer {
+    pub fn new(program: Vec<i64>) -> Self {
+        Self {
+            memory: program,
+            ..Self::default()
+        }
+    }
+
+    /// Queues `value` for the next `In` instruction.
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn extend_input(&mut self, values: impl IntoIterator<Item = i64>) {
+        self.input.extend(values);
+    }
+
+    /// Takes the oldest value from the output queue.
+    pub fn pop_output(&mut self) -> Option<i64> {
+        self.output.pop_front()
+    }
+
+    /// Takes every value from the output queue.
+    pub fn take_output(&mut self) -> Vec<i64> {
+        self.output.drain(..).collect()
+    }
+
+    /// Records the last `capacity` executed instructions from now on, see [`IntCodeComputer::trace`].
+    pub fn enable_trace(&mut self, capacity: usize) {
+        self.tracer = Some(Tracer::new(capacity));
+    }
+
+    pub fn trace(&self) -> Option<&Tracer> {
+        self.tracer.as_ref()
+    }
+
+    /// Makes [`IntCodeComputer::step`] fail with [`Error::StepLimit`] once `limit` instructions have executed, so a
+    /// program stuck in a loop stops and can be inspected through its trace.
+    pub fn limit_steps(&mut self, limit: u64) {
+        self.step_limit = Some(limit);
+    }
+
+    /// Number of instructions executed so far.
+    pub fn executed(&self) -> u64 {
+        self.executed
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    /// Runs until the program halts or waits for input, collecting its output in the output queue.
+    pub fn run(&mut self) -> Result<Status, Error> {
+        loop {
+            match self.run_until_io()? {
+                Status::Output(value) => self.output.push_back(value),
+                status => return Ok(status),
+            }
+        }
+    }
+
+    /// Runs until the program produces a value, halts or waits for input. Queued input is consumed without stopping.
+    pub fn run_until_io(&mut self) -> Result<Status, Error> {
+        loop {
+            if let Some(status) = self.step()? {
+                return Ok(status);
+            }
+        }
+    }
+
+    /// Executes a single instruction. Returns a status if it produced output, halted or is blocked on input, in
+    /// which case the instruction pointer stays on the `In` instruction.
+    pub fn step(&mut self) -> Result<Option<Status>, Error> {
+        if self.halted {
+            return Ok(Some(Status::Halted));
+        }
+
+        if let Some(limit) = self.step_limit.filter(|&limit| self.executed >= limit) {
+            return Err(Error::StepLimit { address: self.instruction_pointer, limit });
+        }
+
+        let (opt_code, parameter_modes) = IntCodeComputer::decode_instruction(self.instruction_pointer, self.read_memory(self.instruction_pointer))?;
+
+        if self.tracer.is_some() {
+            self.begin_trace(opt_code, &parameter_modes)?;
+        }
+
+        let status = match opt_code {
+            OptCode::Add => { self.add(&parameter_modes)?; None }
+            OptCode::Mul => { self.mul(&parameter_modes)?; None }
+            OptCode::In => self.input(&parameter_modes)?,
+            OptCode::Out => Some(self.output(&parameter_modes)?),
+            OptCode::JumpIfTrue => { self.jump_if_true(&parameter_modes)?; None }
+            OptCode::JumpIfFalse => { self.jump_if_false(&parameter_modes)?; None }
+            OptCode::LessThan => { self.less_than(&parameter_modes)?; None }
+            OptCode::Equals => { self.equals(&parameter_modes)?; None }
+            OptCode::AdjustRelativeBase => { self.adjust_relative_base(&parameter_modes)?; None }
+            OptCode::Halt => Some(self.halt()),
+        };
+
+        if status != Some(Status::NeedsInput) {
+            self.executed += 1;
+        }
+
+        if let Some(tracer) = &mut self.tracer {
+            match status {
+                Some(Status::NeedsInput) => tracer.discard(),
+                _ => tracer.finish(),
+            }
+        }
+
+        Ok(status)
+    }
+
+    fn begin_trace(&mut self, opt_code: OptCode, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        let instruction = Instruction::decode(&self.memory, self.instruction_pointer)?;
+        let operands = (0..opt_code.parameters()).map(|index| match opt_code.writes() && index == opt_code.parameters() - 1 {
+            true => self.destination(index, parameter_modes).map(|address| address as i64),
+            false => self.parameter(index, parameter_modes),
+        }).collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(tracer) = &mut self.tracer {
+            tracer.begin(instruction, operands);
+        }
+
+        Ok(())
+    }
+
+    fn add(&mut self, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        let (operand1, operand2, destination) = self.consume_3_int_codes(parameter_modes)?;
+        self.write_memory(destination, operand1 + operand2);
+        self.instruction_pointer += 4;
+        Ok(())
+    }
+
+    fn mul(&mut self, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        let (operand1, operand2, destination) = self.consume_3_int_codes(parameter_modes)?;
+        self.write_memory(destination, operand1 * operand2);
+        self.instruction_pointer += 4;
+        Ok(())
+    }
+
+    fn halt(&mut self) -> Status {
+        self.halted = true;
+        Status::Halted
+    }
+
+    fn input(&mut self, parameter_modes: &[ParameterMode]) -> Result<Option<Status>, Error> {
+        let destination = self.destination(0, parameter_modes)?;
+        let Some(value) = self.input.pop_front() else {
+            return Ok(Some(Status::NeedsInput));
+        };
+
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record_event(Event::Input(value));
+        }
+
+        self.write_memory(destination, value);
+        self.instruction_pointer += 2;
+        Ok(None)
+    }
+
+    fn output(&mut self, parameter_modes: &[ParameterMode]) -> Result<Status, Error> {
+        let param = self.parameter(0, parameter_modes)?;
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record_event(Event::Output(param));
+        }
+
+        self.instruction_pointer += 2;
+        Ok(Status::Output(param))
+    }
+
+    fn jump_if_true(&mut self, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        let (operand1, operand2) = self.consume_2_int_codes(parameter_modes)?;
+        self.instruction_pointer = if operand1 != 0 { self.address(operand2)? } else { self.instruction_pointer + 3 };
+        Ok(())
+    }
+
+    fn jump_if_false(&mut self, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        let (operand1, operand2) = self.consume_2_int_codes(parameter_modes)?;
+        self.instruction_pointer = if operand1 == 0 { self.address(operand2)? } else { self.instruction_pointer + 3 };
+        Ok(())
+    }
+
+    fn less_than(&mut self, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        let (operand1, operand2, destination) = self.consume_3_int_codes(parameter_modes)?;
+        self.write_memory(destination, if operand1 < operand2 { 1 } else { 0 });
+        self.instruction_pointer += 4;
+        Ok(())
+    }
+
+    fn equals(&mut self, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        let (operand1, operand2, destination) = self.consume_3_int_codes(parameter_modes)?;
+        self.write_memory(destination, if operand1 == operand2 { 1 } else { 0 });
+        self.instruction_pointer += 4;
+        Ok(())
+    }
+
+    fn adjust_relative_base(&mut self, parameter_modes: &[ParameterMode]) -> Result<(), Error> {
+        self.relative_base += self.parameter(0, parameter_modes)?;
+        self.instruction_pointer += 2;
+        Ok(())
+    }
+
+    fn consume_3_int_codes(&self, parameter_modes: &[ParameterMode]) -> Result<(i64, i64, usize), Error> {
+        let param1 = self.parameter(0, parameter_modes)?;
+        let param2 = self.parameter(1, parameter_modes)?;
+        let destination = self.destination(2, parameter_modes)?;
+
+        Ok((param1, param2, destination))
+    }
+
+    fn consume_2_int_codes(&self, parameter_modes: &[ParameterMode]) -> Result<(i64, i64), Error> {
+        let param1 = self.parameter(0, parameter_modes)?;
+        let param2 = self.parameter(1, parameter_modes)?;
+
+        Ok((param1, param2))
+    }
+
+    /// Splits `instruction`, found at `address`, into its opcode and the modes of its three possible parameters.
+    pub fn decode_instruction(address: usize, instruction: i64) -> Result<(OptCode, [ParameterMode; 3]), Error> {
+        let mode = |divisor: i64| {
+            let value = (instruction / divisor) % 10;
+            ParameterMode::try_from(value).map_err(|value| Error::UnknownParameterMode { address, value })
+        };
+
+        let opt_code = OptCode::try_from(instruction % 100).map_err(|_| Error::UnknownOpcode { address, value: instruction })?;
+        Ok((opt_code, [mode(100)?, mode(1000)?, mode(10000)?]))
+    }
+
+    /// Value at `address`, memory beyond the program reads as 0.
+    pub fn peek(&self, address: usize) -> i64 {
+        self.read_memory(address)
+    }
+
+    /// Patches the memory before or between runs, e.g. `poke(1, noun)` in 2019 Day 02.
+    pub fn poke(&mut self, address: usize, value: i64) {
+        self.write_memory(address, value);
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    fn read_memory(&self, index: usize) -> i64 {
+        self.memory.get(index).copied().unwrap_or(0)
+    }
+
+    fn write_memory(&mut self, index: usize, value: i64) {
+        if let Some(tracer) = &mut self.tracer {
+            tracer.record_write(index, value);
+        }
+
+        if index >= self.memory.len() {
+            self.memory.resize(index + 1, 0);
+        }
+
+        self.memory[index] = value;
+    }
+
+    fn address(&self, value: i64) -> Result<usize, Error> {
+        usize::try_from(value).map_err(|_| Error::NegativeAddress { address: self.instruction_pointer, value })
+    }
+
+    fn parameter(&self, index: usize, parameter_modes: &[ParameterMode]) -> Result<i64, Error> {
+        let param = self.read_memory(self.instruction_pointer + index + 1);
+
+        Ok(match parameter_modes[index] {
+            ParameterMode::Position => self.read_memory(self.address(param)?),
+            ParameterMode::Immediate => param,
+            ParameterMode::Relative => self.read_memory(self.address(self.relative_base + param)?),
+        })
+    }
+
+    fn destination(&self, index: usize, parameter_modes: &[ParameterMode]) -> Result<usize, Error> {
+        let destination = self.read_memory(self.instruction_pointer + index + 1);
+
+        match parameter_modes[index] {
+            ParameterMode::Position => self.address(destination),
+            ParameterMode::Immediate => Err(Error::ImmediateDestination { address: self.instruction_pointer }),
+            ParameterMode::Relative => self.address(self.relative_base + destination),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::VecDeque;
+
+use crate::utils::intcode::{Error, IntCodeComputer, Status};
+
+/// How [`Network::run`] shares time between the machines.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Schedule {
+    /// Every machine executes one instruction per turn.
+    #[default]
+    RoundRobin,
+    /// Every machine runs until it is blocked on input or halts, then the next one gets its turn.
+    UntilBlocked,
+}
+
+/// Why [`Network::run`] stopped.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    /// Every machine halted.
+    Halted,
+    /// No machine can continue, the listed machines are waiting for input nobody is going to send.
+    Deadlock { blocked: Vec<usize> },
+    /// Every machine of an [addressed](Network::addressed) network keeps reading `-1` from its empty queue.
+    Idle,
+}
+
+/// A packet of an [addressed](Network::addressed) network, sent as the three outputs `to, x, y`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Packet {
+    pub from: usize,
+    pub to: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Where the output of a machine goes.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+enum Routing {
+    /// To the machines it is connected to.
+    #[default]
+    Links,
+    /// To the machine addressed by the packet, see [`Network::addressed`].
+    Addressed,
+}
+
+/// Several IntCode computers with the output of a machine fed into the input of the machines it is connected to,
+/// like the amplifier feedback loop of 2019 Day 07, or routed by address like the packet network of 2019 Day 23.
+#[derive(Debug, Clone, Default)]
+pub struct Network {
+    machines: Vec<IntCodeComputer>,
+    links: Vec<Vec<usize>>,
+    outputs: Vec<VecDeque<i64>>,
+    last_outputs: Vec<Option<i64>>,
+    schedule: Schedule,
+    routing: Routing,
+    /// Output of every machine that does not form a whole packet yet.
+    partial_packets: Vec<Vec<i64>>,
+    /// Packets addressed to no machine of the network.
+    packets: VecDeque<Packet>,
+    /// How often every machine read an empty queue since the last packet was sent anywhere in the network.
+    empty_reads: Vec<u32>,
+}
+
+impl Network {
+    /// A network without any connections, output of every machine is kept until taken with [`Network::take_output`].
+    pub fn new(machines: Vec<IntCodeComputer>, schedule: Schedule) -> Self {
+        let count = machines.len();
+
+        Self {
+            machines,
+            links: vec![Vec::new(); count],
+            outputs: vec![VecDeque::new(); count],
+            last_outputs: vec![None; count],
+            schedule,
+            routing: Routing::Links,
+            partial_packets: vec![Vec::new(); count],
+            packets: VecDeque::new(),
+            empty_reads: vec![0; count],
+        }
+    }
+
+    /// Machine `i` sends its output to machine `i + 1`, the last one back to the first.
+    pub fn ring(machines: Vec<IntCodeComputer>, schedule: Schedule) -> Self {
+        let mut network = Self::new(machines, schedule);
+
+        for from in 0..network.len() {
+            network.connect(from, (from + 1) % network.len());
+        }
+
+        network
+    }
+
+    /// Machine `i` gets `i` as its first input, then every three outputs `to, x, y` form a [`Packet`] whose `x` and `y`
+    /// are queued on machine `to`. Packets to any other address are kept until taken with [`Network::take_packets`].
+    ///
+    /// A machine reading an empty queue gets `-1` instead of blocking. [`Network::run`] stops with [`Outcome::Idle`]
+    /// once every machine read an empty queue twice since the last packet was sent, so each of them went through its
+    /// receive loop without anything to do.
+    pub fn addressed(machines: Vec<IntCodeComputer>, schedule: Schedule) -> Self {
+        let mut network = Self::new(machines, schedule);
+        network.routing = Routing::Addressed;
+
+        for (address, machine) in network.machines.iter_mut().enumerate() {
+            machine.push_input(address as i64);
+        }
+
+        network
+    }
+
+    /// Sends every output of `from` to the input of `to`, in addition to existing connections.
+    pub fn connect(&mut self, from: usize, to: usize) {
+        self.links[from].push(to);
+    }
+
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    pub fn machine(&self, index: usize) -> &IntCodeComputer {
+        &self.machines[index]
+    }
+
+    pub fn machine_mut(&mut self, index: usize) -> &mut IntCodeComputer {
+        &mut self.machines[index]
+    }
+
+    pub fn push_input(&mut self, index: usize, value: i64) {
+        self.machines[index].push_input(value);
+    }
+
+    /// Takes the output of an unconnected machine.
+    pub fn take_output(&mut self, index: usize) -> Vec<i64> {
+        self.outputs[index].drain(..).collect()
+    }
+
+    /// Takes the packets sent to addresses outside the network, oldest first.
+    pub fn take_packets(&mut self) -> Vec<Packet> {
+        self.packets.drain(..).collect()
+    }
+
+    /// Queues a packet on machine `to` from outside the network, e.g. to wake it up after [`Outcome::Idle`].
+    pub fn send(&mut self, to: usize, x: i64, y: i64) {
+        self.machines[to].extend_input([x, y]);
+        self.empty_reads.fill(0);
+    }
+
+    /// The latest value produced by machine `index`, connected or not.
+    pub fn last_output(&self, index: usize) -> Option<i64> {
+        self.last_outputs[index]
+    }
+
+    /// Runs the machines until all of them halted, none of them can continue or an addressed network is idle.
+    pub fn run(&mut self) -> Result<Outcome, Error> {
+        loop {
+            let mut progressed = false;
+
+            for index in 0..self.machines.len() {
+                if self.machines[index].is_halted() {
+                    continue;
+                }
+
+                let result = match self.schedule {
+                    Schedule::RoundRobin => self.step_machine(index),
+                    Schedule::UntilBlocked => self.run_machine(index),
+                };
+
+                progressed |= result.map_err(|error| Error::InMachine { machine: index, error: Box::new(error) })?;
+            }
+
+            if self.is_idle() {
+                return Ok(Outcome::Idle);
+            }
+
+            if !progressed {
+                let blocked = (0..self.machines.len()).filter(|index| !self.machines[*index].is_halted()).collect::<Vec<_>>();
+
+                return Ok(match blocked.is_empty() {
+                    true => Outcome::Halted,
+                    false => Outcome::Deadlock { blocked },
+                });
+            }
+        }
+    }
+
+    /// Executes one instruction of machine `index`, returns whether it did anything.
+    fn step_machine(&mut self, index: usize) -> Result<bool, Error> {
+        Ok(match self.machines[index].step()? {
+            Some(Status::NeedsInput) => self.read_empty_queue(index),
+            Some(Status::Output(value)) => {
+                self.deliver(index, value);
+                true
+            }
+            Some(Status::Halted) | None => true,
+        })
+    }
+
+    /// Runs machine `index` until it is blocked or halts, returns whether it did anything.
+    fn run_machine(&mut self, index: usize) -> Result<bool, Error> {
+        let mut progressed = false;
+
+        loop {
+            match self.machines[index].run_until_io()? {
+                Status::NeedsInput => return Ok(self.read_empty_queue(index) || progressed),
+                Status::Output(value) => self.deliver(index, value),
+                Status::Halted => return Ok(true),
+            }
+
+            progressed = true;
+        }
+    }
+
+    /// Feeds `-1` to a machine of an addressed network waiting for input, returns whether it can continue.
+    fn read_empty_queue(&mut self, index: usize) -> bool {
+        if self.routing != Routing::Addressed {
+            return false;
+        }
+
+        self.machines[index].push_input(-1);
+        self.empty_reads[index] += 1;
+        true
+    }
+
+    fn is_idle(&self) -> bool {
+        self.routing == Routing::Addressed
+            && self.machines.iter().any(|machine| !machine.is_halted())
+            && (0..self.machines.len()).all(|index| self.machines[index].is_halted() || self.empty_reads[index] >= 2)
+    }
+
+    fn deliver(&mut self, from: usize, value: i64) {
+        self.last_outputs[from] = Some(value);
+
+        if self.routing == Routing::Addressed {
+            self.partial_packets[from].push(value);
+
+            if let [to, x, y] = self.partial_packets[from][..] {
+                self.partial_packets[from].clear();
+                self.empty_reads.fill(0);
+
+                match usize::try_from(to).ok().filter(|&to| to < self.machines.len()) {
+                    Some(to) => self.machines[to].extend_input([x, y]),
+                    None => self.packets.push_back(Packet { from, to, x, y }),
+                }
+            }
+
+            return;
+        }
+
+        if self.links[from].is_empty() {
+            self.outputs[from].push_back(value);
+        }
+
+        for to in self.links[from].clone() {
+            self.machines[to].push_input(value);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+use crate::utils::intcode::disassembler::Instruction;
+
+/// Input consumed or output produced by an instruction.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Event {
+    Input(i64),
+    Output(i64),
+}
+
+/// One executed instruction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Step {
+    /// Number of instructions executed before this one.
+    pub index: u64,
+    pub instruction: Instruction,
+    /// The value of every parameter after applying its mode, destinations as the address written to.
+    pub operands: Vec<i64>,
+    /// Every `(address, value)` written to memory.
+    pub writes: Vec<(usize, i64)>,
+    pub event: Option<Event>,
+}
+
+/// Keeps the last `capacity` executed instructions of an [`IntCodeComputer`](super::IntCodeComputer), enabled with
+/// [`IntCodeComputer::enable_trace`](super::IntCodeComputer::enable_trace).
+#[derive(Debug, Clone)]
+pub struct Tracer {
+    capacity: usize,
+    executed: u64,
+    steps: VecDeque<Step>,
+    current: Option<Step>,
+}
+
+impl Tracer {
+    pub fn new(capacity: usize) -> Tracer {
+        Tracer { capacity, executed: 0, steps: VecDeque::with_capacity(capacity), current: None }
+    }
+
+    /// The recorded instructions, oldest first.
+    pub fn steps(&self) -> impl Iterator<Item = &Step> {
+        self.steps.iter()
+    }
+
+    /// Number of instructions executed since tracing was enabled, including those no longer recorded.
+    pub fn executed(&self) -> u64 {
+        self.executed
+    }
+
+    pub(super) fn begin(&mut self, instruction: Instruction, operands: Vec<i64>) {
+        self.current = Some(Step { index: self.executed, instruction, operands, writes: Vec::new(), event: None });
+    }
+
+    pub(super) fn record_write(&mut self, address: usize, value: i64) {
+        if let Some(step) = &mut self.current {
+            step.writes.push((address, value));
+        }
+    }
+
+    pub(super) fn record_event(&mut self, event: Event) {
+        if let Some(step) = &mut self.current {
+            step.event = Some(event);
+        }
+    }
+
+    /// Stores the current instruction, dropping the oldest one when full.
+    pub(super) fn finish(&mut self) {
+        if let Some(step) = self.current.take() {
+            self.executed += 1;
+
+            if self.capacity == 0 {
+                return;
+            }
+
+            if self.steps.len() == self.capacity {
+                self.steps.pop_front();
+            }
+            self.steps.push_back(step);
+        }
+    }
+
+    /// Forgets the current instruction, it did not execute because it is waiting for input.
+    pub(super) fn discard(&mut self) {
+        self.current = None;
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut line = format!("#{:<8} {:>5}: {:<32}", self.index, self.instruction.address, self.instruction.to_string());
+
+        if !self.operands.is_empty() {
+            line += &format!(" ; {}", self.operands.iter().map(i64::to_string).collect::<Vec<_>>().join(", "));
+        }
+
+        for (address, value) in &self.writes {
+            line += &format!(" ; [{address}] = {value}");
+        }
+
+        match self.event {
+            Some(Event::Input(value)) => line += &format!(" ; in {value}"),
+            Some(Event::Output(value)) => line += &format!(" ; out {value}"),
+            None => {}
+        }
+
+        write!(f, "{}", line.trim_end())
+    }
+}
+
+impl Display for Tracer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{step}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 pub mod tree;
 pub mod grid;
 pub mod math;
-pub mod a_star_impl;
+pub mod parse;
+pub mod search;
+pub mod intcode;
 pub mod a_star;
\ No newline at end of file
@@ -0,0 +1,143 @@
+//! Building blocks for `FromStr` implementations of puzzle inputs.
+//!
+//! Everything hands out subslices of its input and reports errors as [`Error::Located`] relative to the input it
+//! was given, so results can be chained with [`crate::aoc::Context::within`] to point into the whole puzzle input.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::aoc::{Context, Error};
+
+/// Blocks of lines separated by blank lines, without their trailing line breaks. Works for `\n` and `\r\n`.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+
+        if content.trim().is_empty() {
+            if let Some(section_start) = start.take() {
+                sections.push(&input[section_start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(section_start) = start {
+        sections.push(&input[section_start..end]);
+    }
+
+    sections
+}
+
+/// Parses `span` of `input`, pointing at it when it is not a valid `T`.
+pub fn value<T>(input: &str, span: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let span = span.trim();
+    span.parse::<T>().map_err(|error| Error::at(error.to_string(), input, span))
+}
+
+/// Every run of digits in `input`, ignoring whatever is around them.
+pub fn unsigned<T>(input: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    numbers(input, false)
+}
+
+/// Every integer in `input`, a `-` directly in front of the digits makes it negative.
+pub fn signed<T>(input: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    numbers(input, true)
+}
+
+fn numbers<T>(input: &str, signed: bool) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = input.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = if signed && i > 0 && bytes[i - 1] == b'-' { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        numbers.push(value(input, &input[start..i])?);
+    }
+
+    Ok(numbers)
+}
+
+/// `separator` separated values, each trimmed, e.g. `list::<u32>("0,1,5,4", ',')`.
+pub fn list<T>(input: &str, separator: char) -> Result<Vec<T>, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input.split(separator).map(|item| value(input, item)).collect()
+}
+
+/// The text after `label:` on the first line starting with `label`, e.g. `field_str(input, "Register A")`.
+pub fn field_str<'a>(input: &'a str, label: &str) -> Result<&'a str, Error> {
+    input.lines()
+        .find_map(|line| line.trim_start().strip_prefix(label)?.trim_start().strip_prefix(':'))
+        .map(str::trim)
+        .ok_or_else(|| Error::Parse(format!("missing \"{label}:\"")))
+}
+
+/// [`field_str`] parsed as `T`.
+pub fn field<T>(input: &str, label: &str) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let field = field_str(input, label)?;
+    value(field, field).within(input, field)
+}
+
+/// One row per line, every character mapped by `cell`. Characters it rejects and rows of differing length are
+/// reported with their position.
+pub fn grid<T>(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Vec<Vec<T>>, Error> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for line in input.lines() {
+        let row = line.char_indices()
+            .map(|(i, char)| cell(char).ok_or_else(|| {
+                Error::at(format!("unexpected character '{char}'"), input, &line[i..i + char.len_utf8()])
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(first) = rows.first() {
+            if first.len() != row.len() {
+                return Err(Error::at(format!("expected {} columns, found {}", first.len(), row.len()), input, line));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
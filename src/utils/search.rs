@@ -0,0 +1,304 @@
+//! Graph searches over implicit graphs, described by a start state and a successor function.
+//!
+//! States only need to be `Clone + Eq + Hash`, weighted searches work with any [`Cost`], e.g. `u32`, `usize` or
+//! `u64`, instead of a hard-coded `i32`.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+
+/// Anything that can be summed up along a path and compared.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Zero {}
+
+impl<T: Copy + Ord + Add<Output = T> + Zero> Cost for T {}
+
+/// Every state reached by [`bfs`], with its distance in steps and the state it was reached from.
+#[derive(Debug, Clone)]
+pub struct Bfs<State> {
+    pub distances: HashMap<State, usize>,
+    pub predecessors: HashMap<State, State>,
+}
+
+impl<State: Clone + Eq + Hash> Bfs<State> {
+    /// Shortest path from the start to `target`, both included.
+    pub fn path_to(&self, target: &State) -> Option<Vec<State>> {
+        self.distances.contains_key(target).then(|| backtrack(&self.predecessors, target.clone()))
+    }
+}
+
+/// Breadth first search visiting everything reachable from `start`.
+pub fn bfs<State, Successors, I>(start: State, mut successors: Successors) -> Bfs<State>
+where
+    State: Clone + Eq + Hash,
+    Successors: FnMut(&State) -> I,
+    I: IntoIterator<Item = State>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[&current] + 1;
+
+        for next in successors(&current) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance);
+                predecessors.insert(next.clone(), current.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Bfs { distances, predecessors }
+}
+
+/// Breadth first search that stops at the first state matching `is_goal` and returns the path to it.
+pub fn bfs_until<State, Successors, Goal, I>(start: State, mut successors: Successors, mut is_goal: Goal) -> Option<Vec<State>>
+where
+    State: Clone + Eq + Hash,
+    Successors: FnMut(&State) -> I,
+    Goal: FnMut(&State) -> bool,
+    I: IntoIterator<Item = State>,
+{
+    let mut predecessors = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        if is_goal(&current) {
+            return Some(backtrack(&predecessors, current));
+        }
+
+        for next in successors(&current) {
+            if seen.insert(next.clone()) {
+                predecessors.insert(next.clone(), current.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Depth first search, returns every state reachable from `start` in the order it was visited.
+pub fn dfs<State, Successors, I>(start: State, mut successors: Successors) -> Vec<State>
+where
+    State: Clone + Eq + Hash,
+    Successors: FnMut(&State) -> I,
+    I: IntoIterator<Item = State>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(current) = stack.pop() {
+        if !seen.insert(current.clone()) {
+            continue;
+        }
+
+        let mut next = successors(&current).into_iter().filter(|next| !seen.contains(next)).collect::<Vec<_>>();
+        // keeps the successor order, the first one is visited first
+        next.reverse();
+        stack.extend(next);
+        order.push(current);
+    }
+
+    order
+}
+
+/// Lowest cost to every state reachable from the start of a [`dijkstra_all`] run.
+#[derive(Debug, Clone)]
+pub struct Dijkstra<State, C> {
+    pub costs: HashMap<State, C>,
+    pub predecessors: HashMap<State, State>,
+}
+
+impl<State: Clone + Eq + Hash, C> Dijkstra<State, C> {
+    /// One cheapest path from the start to `target`, both included.
+    pub fn path_to(&self, target: &State) -> Option<Vec<State>> {
+        self.costs.contains_key(target).then(|| backtrack(&self.predecessors, target.clone()))
+    }
+}
+
+/// Dijkstra's algorithm from `start` until the first state matching `is_goal` is settled. Returns the path to it and
+/// its cost.
+pub fn dijkstra<State, C, Successors, Goal, I>(start: State, successors: Successors, is_goal: Goal) -> Option<(Vec<State>, C)>
+where
+    State: Clone + Eq + Hash + Ord,
+    C: Cost,
+    Successors: FnMut(&State) -> I,
+    Goal: FnMut(&State) -> bool,
+    I: IntoIterator<Item = (State, C)>,
+{
+    let (search, goal) = run_dijkstra(start, successors, is_goal);
+    let goal = goal?;
+    let cost = search.costs[&goal];
+
+    Some((backtrack(&search.predecessors, goal), cost))
+}
+
+/// Dijkstra's algorithm settling every state reachable from `start`.
+pub fn dijkstra_all<State, C, Successors, I>(start: State, successors: Successors) -> Dijkstra<State, C>
+where
+    State: Clone + Eq + Hash + Ord,
+    C: Cost,
+    Successors: FnMut(&State) -> I,
+    I: IntoIterator<Item = (State, C)>,
+{
+    run_dijkstra(start, successors, |_| false).0
+}
+
+fn run_dijkstra<State, C, Successors, Goal, I>(start: State, mut successors: Successors, mut is_goal: Goal) -> (Dijkstra<State, C>, Option<State>)
+where
+    State: Clone + Eq + Hash + Ord,
+    C: Cost,
+    Successors: FnMut(&State) -> I,
+    Goal: FnMut(&State) -> bool,
+    I: IntoIterator<Item = (State, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::zero())]);
+    let mut predecessors = HashMap::new();
+    let mut settled = HashSet::new();
+    let mut queue = BinaryHeap::from([Reverse((C::zero(), start))]);
+
+    while let Some(Reverse((cost, current))) = queue.pop() {
+        if !settled.insert(current.clone()) {
+            continue;
+        }
+
+        if is_goal(&current) {
+            return (Dijkstra { costs, predecessors }, Some(current));
+        }
+
+        for (next, step) in successors(&current) {
+            let next_cost = cost + step;
+
+            if costs.get(&next).is_none_or(|known| next_cost < *known) {
+                costs.insert(next.clone(), next_cost);
+                predecessors.insert(next.clone(), current.clone());
+                queue.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+
+    (Dijkstra { costs, predecessors }, None)
+}
+
+/// Bidirectional Dijkstra between `start` and `goal`. `predecessors` yields the states that lead to a state with
+/// the cost of that edge, for undirected graphs it is the same function as `successors`.
+pub fn bidirectional<State, C, Successors, Predecessors, I, J>(
+    start: State,
+    goal: State,
+    mut successors: Successors,
+    mut predecessors: Predecessors,
+) -> Option<(Vec<State>, C)>
+where
+    State: Clone + Eq + Hash + Ord,
+    C: Cost,
+    Successors: FnMut(&State) -> I,
+    Predecessors: FnMut(&State) -> J,
+    I: IntoIterator<Item = (State, C)>,
+    J: IntoIterator<Item = (State, C)>,
+{
+    let mut forward = Frontier::new(start);
+    let mut backward = Frontier::new(goal);
+    let mut best: Option<(C, State)> = None;
+
+    while let (Some(forward_min), Some(backward_min)) = (forward.peek(), backward.peek()) {
+        if best.as_ref().is_some_and(|(cost, _)| forward_min + backward_min >= *cost) {
+            break;
+        }
+
+        let expanded = match forward_min <= backward_min {
+            true => forward.expand(&mut successors),
+            false => backward.expand(&mut predecessors),
+        };
+
+        for state in expanded {
+            if let (Some(a), Some(b)) = (forward.costs.get(&state), backward.costs.get(&state)) {
+                if best.as_ref().is_none_or(|(cost, _)| *a + *b < *cost) {
+                    best = Some((*a + *b, state));
+                }
+            }
+        }
+    }
+
+    let (cost, meeting) = best?;
+    let mut path = backtrack(&forward.predecessors, meeting.clone());
+    let mut state = meeting;
+    while let Some(next) = backward.predecessors.get(&state) {
+        path.push(next.clone());
+        state = next.clone();
+    }
+
+    Some((path, cost))
+}
+
+/// One direction of a [`bidirectional`] search.
+struct Frontier<State, C> {
+    costs: HashMap<State, C>,
+    predecessors: HashMap<State, State>,
+    settled: HashSet<State>,
+    queue: BinaryHeap<Reverse<(C, State)>>,
+}
+
+impl<State: Clone + Eq + Hash + Ord, C: Cost> Frontier<State, C> {
+    fn new(start: State) -> Self {
+        Self {
+            costs: HashMap::from([(start.clone(), C::zero())]),
+            predecessors: HashMap::new(),
+            settled: HashSet::new(),
+            queue: BinaryHeap::from([Reverse((C::zero(), start))]),
+        }
+    }
+
+    /// Cost of the next state to settle, skipping stale queue entries.
+    fn peek(&mut self) -> Option<C> {
+        while let Some(Reverse((cost, state))) = self.queue.peek() {
+            if !self.settled.contains(state) {
+                return Some(*cost);
+            }
+            self.queue.pop();
+        }
+
+        None
+    }
+
+    /// Settles the cheapest state and returns every state whose cost changed, including the settled one.
+    fn expand<I: IntoIterator<Item = (State, C)>>(&mut self, neighbours: &mut impl FnMut(&State) -> I) -> Vec<State> {
+        let Some(Reverse((cost, current))) = self.queue.pop() else {
+            return Vec::new();
+        };
+        self.settled.insert(current.clone());
+
+        let mut changed = vec![current.clone()];
+        for (next, step) in neighbours(&current) {
+            let next_cost = cost + step;
+
+            if self.costs.get(&next).is_none_or(|known| next_cost < *known) {
+                self.costs.insert(next.clone(), next_cost);
+                self.predecessors.insert(next.clone(), current.clone());
+                self.queue.push(Reverse((next_cost, next.clone())));
+                changed.push(next);
+            }
+        }
+
+        changed
+    }
+}
+
+/// Follows `predecessors` back from `target` and returns the path in forward order.
+fn backtrack<State: Clone + Eq + Hash>(predecessors: &HashMap<State, State>, target: State) -> Vec<State> {
+    let mut path = vec![target];
+
+    while let Some(previous) = predecessors.get(path.last().expect("path starts with the target")) {
+        path.push(previous.clone());
+    }
+
+    path.reverse();
+    path
+}
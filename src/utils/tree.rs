@@ -1,212 +1,182 @@
-use std::cell::RefCell;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::fmt::{Debug, Display, Formatter};
-use std::hash::Hash;
-use std::rc::{Rc, Weak};
-use crate::utils::grid::{Coord, Grid};
-
-#[derive(Default, Clone)]
-pub struct Node<T> {
-    pub value: T,
-    pub parent: Option<Weak<RefCell<Node<T>>>>,
-    pub children: Vec<Rc<RefCell<Node<T>>>>,
-}
 
+use crate::utils::grid::{Coord, Grid};
+use crate::utils::search;
 
-impl<T: Debug + Clone> Debug for Node<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let parent_name = if let Some(parent) = &self.parent {
-            parent.upgrade().map(|p| p.borrow().value.clone())
-        } else {
-            None
-        };
+/// Handle of a node inside a [`Graph`], only meaningful for the graph that created it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NodeId(usize);
 
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    parents: Vec<NodeId>,
+    children: Vec<NodeId>,
+}
 
-        let mut k = f.debug_struct("Node");
-        k.field("value", &self.value);
-        k.field("parent", &parent_name);
-        k.field("children", &self.children.iter().map(|c| c.borrow()).collect::<Vec<_>>());
+/// Directed graph with its nodes stored in a `Vec`. Edges point from parent to child, a node can have several
+/// parents, so trees, DAGs and graphs with cycles share the same type.
+#[derive(Debug, Clone)]
+pub struct Graph<T> {
+    nodes: Vec<Node<T>>,
+}
 
-        k.finish()
+impl<T> Default for Graph<T> {
+    fn default() -> Self {
+        Self { nodes: Vec::new() }
     }
 }
 
-
-impl<T: Clone + PartialEq + Debug> Node<T> {
-    pub fn new(value: T) -> Node<T> {
-        Node {
-            value,
-            parent: None,
-            children: Vec::new(),
-        }
+impl<T> Graph<T> {
+    pub fn new() -> Graph<T> {
+        Self::default()
     }
 
-    pub fn add_child(&mut self, value: T) {
-        self.children.push(Rc::new(RefCell::new(Node::new(value.clone()))));
+    pub fn add_node(&mut self, value: T) -> NodeId {
+        self.nodes.push(Node { value, parents: Vec::new(), children: Vec::new() });
+        NodeId(self.nodes.len() - 1)
     }
 
-    pub fn try_from_grid(grid: &Grid<T>, predicate: impl Fn(&T) -> bool, is_adjacent: impl Fn(&T, &T, &Coord) -> bool, overlapping_trees: bool) -> Vec<Rc<RefCell<Node<T>>>> {
-        let mut nodes = Vec::new();
-        let starting_points = grid.find_all(&predicate);
-
-        for (x, y) in starting_points {
-            if let Some(start_value) = grid.get((x, y)) {
-                let root = Rc::new(RefCell::new(Node {
-                    value: start_value.clone(),
-                    parent: None,
-                    children: Vec::new(),
-                }));
-
-                let mut visited = vec![vec![false; grid.width()]; grid.height()];
-                visited[y][x] = true;
+    /// Adds an edge from `parent` to `child`. Adding the same edge twice keeps both, like `DDD = (DDD, DDD)` in 2023 Day 08.
+    pub fn add_edge(&mut self, parent: NodeId, child: NodeId) {
+        self.nodes[parent.0].children.push(child);
+        self.nodes[child.0].parents.push(parent);
+    }
 
-                Self::build_graph(grid, &is_adjacent, root.clone(), &mut visited, x, y, overlapping_trees);
+    /// Adds `value` as a new child of `parent`.
+    pub fn add_child(&mut self, parent: NodeId, value: T) -> NodeId {
+        let child = self.add_node(value);
+        self.add_edge(parent, child);
+        child
+    }
 
-                nodes.push(root);
-            }
-        }
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
 
-        nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
     }
 
-    fn build_graph(grid: &Grid<T>, is_adjacent: &impl Fn(&T, &T, &Coord) -> bool, current: Rc<RefCell<Node<T>>>, visited: &mut Vec<Vec<bool>>, x: usize, y: usize, overlapping_trees: bool) {
-        let directions = [(-1, 0), (1, 0), (0, -1), (0, 1)]; // Cardinal directions
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
 
-        for &(dx, dy) in &directions {
-            let nx = (x as isize + dx) as usize;
-            let ny = (y as isize + dy) as usize;
+    pub fn value(&self, id: NodeId) -> &T {
+        &self.nodes[id.0].value
+    }
 
-            if nx < grid.width() && ny < grid.height() && (overlapping_trees || !visited[ny][nx]) {
-                if let Some(neighbor_value) = grid.get((nx, ny)) {
-                    let current_value = current.borrow().value.clone();
-                    let coord = (nx, ny);
+    pub fn value_mut(&mut self, id: NodeId) -> &mut T {
+        &mut self.nodes[id.0].value
+    }
 
-                    if is_adjacent(&current_value, neighbor_value, &coord) {
-                        let child = Rc::new(RefCell::new(Node {
-                            value: neighbor_value.clone(),
-                            parent: Some(Rc::downgrade(&current)),
-                            children: Vec::new(),
-                        }));
+    /// The first parent of `id`, the only one in a tree.
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parents.first().copied()
+    }
 
-                        current.borrow_mut().children.push(Rc::clone(&child));
+    pub fn parents(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id.0].parents
+    }
 
-                        visited[ny][nx] = true;
-                        Self::build_graph(grid, is_adjacent, child, visited, nx, ny, overlapping_trees);
-                    }
-                }
-            }
-        }
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id.0].children
     }
 
-    /// Breadth first search but it also traverses in parent direction
-    pub fn advanced_breadth_first_search(start: Rc<RefCell<Node<T>>>, end: Rc<RefCell<Node<T>>>) -> Vec<Rc<RefCell<Node<T>>>> {
-        let mut visited = HashMap::new();
-        let mut queue = VecDeque::new();
-
-        let start_parent = if let Some(start_parent) = start.borrow().clone().parent {
-            start_parent.upgrade()
-        } else {
-            None
-        };
-
-        queue.push_back((start.clone(), start_parent));
-
-        while let Some((current, parent)) = queue.pop_front() {
-            if Rc::ptr_eq(&current, &end) {
-                let mut path = vec![current.clone()];
-                let mut previous: Option<Rc<RefCell<Node<T>>>> = parent;
-
-                // backtrace from 'end' node to 'start'
-                while let Some(prev_node) = previous {
-                    path.push(prev_node.clone());
-                    let s = visited.get(&(Rc::as_ptr(&prev_node)));
-                    if let Some(s) = s {
-                        previous = Some(Rc::clone(s));
-                    } else {
-                        previous = None;
-                    }
-
-                    if Rc::ptr_eq(&prev_node, &start) {
-                        break;
-                    }
-                }
+    /// Every edge as `(parent, child)`.
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.ids().flat_map(|id| self.children(id).iter().map(move |child| (id, *child)))
+    }
 
-                path.reverse();
-                return path;
-            }
+    /// Nodes without a parent.
+    pub fn roots(&self) -> Vec<NodeId> {
+        self.ids().filter(|id| self.parents(*id).is_empty()).collect()
+    }
 
-            if let Some(parent) = parent {
-                visited.insert(Rc::as_ptr(&current), Rc::clone(&parent));
-            }
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<NodeId> {
+        self.ids().find(|id| predicate(self.value(*id)))
+    }
 
-            let current_borrow = current.borrow();
+    pub fn find_all(&self, predicate: impl Fn(&T) -> bool) -> Vec<NodeId> {
+        self.ids().filter(|id| predicate(self.value(*id))).collect()
+    }
 
-            if let Some(ref parent_weak) = current_borrow.parent {
-                if let Some(parent) = parent_weak.upgrade() {
-                    if !visited.contains_key(&Rc::as_ptr(&parent)) {
-                        queue.push_back((parent.clone(), Some(Rc::clone(&current))));
-                    }
-                }
-            }
+    /// Every node reachable from `start` by following children, in breadth first order.
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = HashSet::from([start]);
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
 
-            for child in &current_borrow.children {
-                if !visited.contains_key(&Rc::as_ptr(child)) {
-                    queue.push_back((child.clone(), Some(Rc::clone(&current))));
-                }
-            }
+        while let Some(current) = queue.pop_front() {
+            order.push(current);
+            queue.extend(self.children(current).iter().filter(|child| seen.insert(**child)));
         }
 
-        Vec::new() // Return empty vector if no path found
+        order
     }
 
-
-    pub fn search_in_tree_predicate(&self, predicate: fn(&T) -> bool) -> Option<Rc<RefCell<Node<T>>>> {
-        self.search_in_tree_accumulated(predicate, &mut HashSet::new())
+    /// Every node reachable from `start` by following children, in depth first order.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        search::dfs(start, |current| self.children(*current).to_vec())
     }
 
-    fn search_in_tree_accumulated(&self, predicate: fn(&T) -> bool, hashset: &mut HashSet<*mut Node<T>>) -> Option<Rc<RefCell<Node<T>>>> {
-        let children = self.children.clone();
-
-        for child in children {
-            if hashset.contains(&child.as_ptr()) {
-                continue;
-            }
-
-            hashset.insert(child.as_ptr());
-
-            if predicate(&child.borrow().value) {
-                return Some(child);
-            }
-
-            if let Some(found) = child.borrow().search_in_tree_accumulated(predicate, hashset) {
-                return Some(found);
-            }
-        }
-
-        None
+    /// `id` followed by its parent, grandparent and so on up to the root, following the first parent of each node.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        std::iter::successors(Some(id), |current| self.parent(*current))
     }
-}
-
-pub fn search_in_tree_predicate_all<T: Display + Hash + Debug + Eq + PartialEq + Clone>(own: &Rc<RefCell<Node<T>>>, predicate: fn(&T) -> bool) -> Vec<Rc<RefCell<Node<T>>>> {
-    let mut a = vec![];
 
-    search_in_tree_accumulated_all(own, predicate, &mut HashSet::new(), &mut a);
-    a
-}
+    /// Number of edges between `id` and its root.
+    pub fn depth(&self, id: NodeId) -> usize {
+        self.ancestors(id).count() - 1
+    }
 
-fn search_in_tree_accumulated_all<T: Display + Hash + Debug + Eq + PartialEq + Clone>(own: &Rc<RefCell<Node<T>>>, predicate: fn(&T) -> bool, hashset: &mut HashSet<T>, found_nodes: &mut Vec<Rc<RefCell<Node<T>>>>) {
-    let children = own.borrow().children.clone();
+    /// The deepest node that is an ancestor of both `a` and `b`, each node counting as its own ancestor.
+    pub fn lowest_common_ancestor(&self, a: NodeId, b: NodeId) -> Option<NodeId> {
+        let ancestors_of_a = self.ancestors(a).collect::<HashSet<_>>();
+        self.ancestors(b).find(|ancestor| ancestors_of_a.contains(ancestor))
+    }
 
-    if predicate(&own.borrow().value) {
-        found_nodes.push(Rc::clone(own));
+    /// Shortest path from `from` to `to`, both included, moving along edges in either direction.
+    pub fn path(&self, from: NodeId, to: NodeId) -> Option<Vec<NodeId>> {
+        search::bfs_until(from, |current| {
+            self.children(*current).iter().chain(self.parents(*current)).copied().collect::<Vec<_>>()
+        }, |current| *current == to)
     }
+}
 
-    for child in children {
-        if hashset.contains(&child.borrow().value) {
-            continue;
+impl<T: Clone> Graph<T> {
+    /// Builds a graph from every cell of `grid` reachable from a cell matching `predicate`, with an edge from a cell
+    /// to each of its four neighbours for which `is_adjacent(cell, neighbour, neighbour_coord)` holds.
+    ///
+    /// Each cell becomes a single node, so trees starting at different roots share their common parts. Returns the
+    /// graph and the roots in the order of [`Grid::find_all`].
+    pub fn from_grid(grid: &Grid<T>, predicate: impl Fn(&T) -> bool, is_adjacent: impl Fn(&T, &T, &Coord) -> bool) -> (Graph<T>, Vec<NodeId>) {
+        let mut graph = Graph::new();
+        let mut ids: HashMap<Coord, NodeId> = HashMap::new();
+        let mut stack = Vec::new();
+
+        let roots = grid.find_all(&predicate).into_iter().map(|coord| {
+            *ids.entry(coord).or_insert_with(|| {
+                stack.push(coord);
+                graph.add_node(grid[coord].clone())
+            })
+        }).collect();
+
+        while let Some(coord) = stack.pop() {
+            let current = ids[&coord];
+
+            for (neighbour, value) in grid.neighbours_4(coord) {
+                if is_adjacent(&grid[coord], value, &neighbour) {
+                    let child = *ids.entry(neighbour).or_insert_with(|| {
+                        stack.push(neighbour);
+                        graph.add_node(value.clone())
+                    });
+
+                    graph.add_edge(current, child);
+                }
+            }
         }
 
-        hashset.insert(child.borrow().value.clone());
-        search_in_tree_accumulated_all(&child, predicate, hashset, found_nodes);
+        (graph, roots)
     }
-}
\ No newline at end of file
+}
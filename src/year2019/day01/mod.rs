@@ -28,11 +28,11 @@ impl crate::aoc::Day for Day {
     }
 
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
-        return Ok(self.values.iter().map(|s| s / 3 - 2).sum::<u32>() as i32);
+        Ok(self.values.iter().map(|s| s / 3 - 2).sum::<u32>() as i32)
     }
 
     fn solution2(&mut self) -> anyhow::Result<Self::Output> {
-        return Ok(self.values.iter().map(|s| {
+        Ok(self.values.iter().map(|s| {
             let value = (*s as i32) / 3 - 2;
             let mut negative_fuel = value / 3 - 2;
             let mut sum = value;
@@ -47,7 +47,7 @@ impl crate::aoc::Day for Day {
             }
 
             sum
-        }).sum::<i32>());
+        }).sum::<i32>())
     }
 }
 
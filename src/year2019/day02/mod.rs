@@ -1,15 +1,19 @@
 use std::str::FromStr;
 
+use rayon::prelude::*;
+
 use crate::aoc::Error;
+use crate::utils::intcode::{self, IntCodeComputer};
 
 #[derive(Default, Clone)]
 pub struct Day {
-    pub values: Vec<usize>,
-    pub parameter: Option<(usize, usize)>
+    pub values: Vec<i64>,
+    /// Noun and verb patched into the program with [`IntCodeComputer::poke`], the examples run unpatched.
+    pub patch: Option<(i64, i64)>,
 }
 
 impl crate::aoc::Day for Day {
-    type Output = i32;
+    type Output = i64;
 
     fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
         vec![
@@ -25,64 +29,34 @@ impl crate::aoc::Day for Day {
         vec![]
     }
 
-    fn after_test_2(&mut self) {
-        self.parameter = None;
-    }
-
     fn after_test_1(&mut self) {
-        self.parameter = Some((12, 2));
+        // restore the "1202 program alarm" state
+        self.patch = Some((12, 2));
     }
 
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
-        let program = &mut self.values;
-        let mut index = 0;
+        let mut computer = IntCodeComputer::new(self.values.clone());
 
-        if let Some(parameter) = self.parameter {
-            program[1] = parameter.0;
-            program[2] = parameter.1;
+        if let Some((noun, verb)) = self.patch {
+            computer.poke(1, noun);
+            computer.poke(2, verb);
         }
 
-        loop {
-            let opt_code = program[index];
-            match opt_code {
-                1 => {
-                    let window = program[index..index + 4].iter().copied().clone().collect::<Vec<usize>>();
-
-                    if let [_, position_one, position_two, target] = window[..] {
-                        program[target] = program[position_one] + program[position_two];
-                    }
-                }
-                2 => {
-                    let window = program[index..index + 4].iter().copied().clone().collect::<Vec<usize>>();
-                    if let [_, position_one, position_two, target] = window[..] {
-                        program[target] = program[position_one] * program[position_two];
-                    }
-                }
-                99 => break,
-                _ => {},
-            }
-
-            index += 4;
-        }
+        computer.run()?;
 
-        Ok(program[0] as i32)
+        Ok(computer.peek(0))
     }
 
     fn solution2(&mut self) -> anyhow::Result<Self::Output> {
         let target_string = 19690720;
+        let candidates = (0..=99).into_par_iter().flat_map(|noun| (0..=99).into_par_iter().map(move |verb| (noun, verb)));
 
-        for i in 0..=99 {
-            for j in 0..=99 {
-                let mut c = Day::from_str(include_str!("./input.txt"))?;
-                c.parameter = Some((i, j));
-
-                if target_string == c.solution1()? {
-                    return Ok((100 * i + j) as i32);
-                }
-            }
-        }
+        let (noun, verb) = intcode::search_inputs(&self.values, candidates, |computer, (noun, verb)| {
+            computer.poke(1, *noun);
+            computer.poke(2, *verb);
+        }, |computer| computer.peek(0) == target_string).ok_or(Error::NoSolutionFound)?;
 
-        Err(Error::NoSolutionFound.into())
+        Ok(100 * noun + verb)
     }
 }
 
@@ -91,11 +65,8 @@ impl FromStr for Day {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            values: s.split(',')
-                .map(|s| s.parse::<usize>())
-                .filter_map(|s| s.ok())
-                .collect(),
-            parameter: None
+            values: intcode::parse_program(s)?,
+            patch: None,
         })
     }
 }
\ No newline at end of file
@@ -180,10 +180,10 @@ impl FromStr for Day {
 fn to_direction(direction_str: &str) -> Result<Direction, Error> {
     let (direction, amount) = direction_str.split_at(1);
     match direction {
-        "L" => Ok(Direction::Left(amount.parse::<i32>().map_err(|_| Error::StringParse(amount.to_string()))?)),
-        "R" => Ok(Direction::Right(amount.parse::<i32>().map_err(|_| Error::StringParse(amount.to_string()))?)),
-        "U" => Ok(Direction::Up(amount.parse::<i32>().map_err(|_| Error::StringParse(amount.to_string()))?)),
-        "D" => Ok(Direction::Down(amount.parse::<i32>().map_err(|_| Error::StringParse(amount.to_string()))?)),
-        s => Err(Error::StringParse(s.to_string())),
+        "L" => Ok(Direction::Left(amount.parse::<i32>().map_err(|_| Error::Parse(amount.to_string()))?)),
+        "R" => Ok(Direction::Right(amount.parse::<i32>().map_err(|_| Error::Parse(amount.to_string()))?)),
+        "U" => Ok(Direction::Up(amount.parse::<i32>().map_err(|_| Error::Parse(amount.to_string()))?)),
+        "D" => Ok(Direction::Down(amount.parse::<i32>().map_err(|_| Error::Parse(amount.to_string()))?)),
+        s => Err(Error::Parse(s.to_string())),
     }
 }
\ No newline at end of file
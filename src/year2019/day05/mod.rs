@@ -1,17 +1,14 @@
 use std::str::FromStr;
 use crate::aoc::Error;
-use crate::year2019::day05::int_code_computer::{IntCodeComputer, IO};
-
-mod int_code_computer;
+use crate::utils::intcode::{self, IntCodeComputer};
 
 #[derive(Default, Clone)]
 pub struct Day {
-    pub values: Vec<i32>,
-    pub parameter: Option<(i32, i32)>
+    pub values: Vec<i64>,
 }
 
 impl crate::aoc::Day for Day {
-    type Output = i32;
+    type Output = i64;
 
     fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
         vec![]
@@ -23,18 +20,22 @@ impl crate::aoc::Day for Day {
 
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
         let program = &mut self.values;
-        let mut computer = IntCodeComputer::new(program.clone(), IO { value: 1 });
-        computer.run();
+        let mut computer = IntCodeComputer::new(program.clone());
+        computer.push_input(1);
+        computer.run()?;
 
-        Ok(computer.io.value)
+        // the diagnostic code is the last output, everything before it are test results
+        computer.take_output().pop().ok_or(Error::NoSolutionFound.into())
     }
 
     fn solution2(&mut self) -> anyhow::Result<Self::Output> {
         let program = &mut self.values;
-        let mut computer = IntCodeComputer::new(program.clone(), IO { value: 5 });
-        computer.run();
+        let mut computer = IntCodeComputer::new(program.clone());
+        computer.push_input(5);
+        computer.run()?;
 
-        Ok(computer.io.value)
+        // the diagnostic code is the last output, everything before it are test results
+        computer.take_output().pop().ok_or(Error::NoSolutionFound.into())
     }
 }
 
@@ -43,11 +44,7 @@ impl FromStr for Day {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            values: s.split(',')
-                .map(|s| s.parse::<i32>())
-                .filter_map(|s| s.ok())
-                .collect(),
-            parameter: None
+            values: intcode::parse_program(s)?,
         })
     }
 }
\ No newline at end of file
@@ -1,27 +1,41 @@
-use std::fs;
+use std::str::FromStr;
 
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    calories: Vec<u32>,
+}
 
-pub struct Day1;
+impl FromStr for Day {
+    type Err = crate::aoc::Error;
 
-impl crate::year2022::Day for Day1 {
-    fn date(&self) -> (i32, i32) { (1, 2022) }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut calories = s.replace("\r\n", "\n")
+            .split("\n\n")
+            .map(|elf| elf.lines().map(|line| line.trim().parse::<u32>()).sum())
+            .collect::<Result<Vec<u32>, _>>()?;
 
-    fn run(&self) {
-        let input = fs::read_to_string("src/year_2022/day1/input.txt")
-            .unwrap();
+        calories.sort_by(|a, b| b.cmp(a));
 
-        let mut lines: Vec<u32> = input
-            .split("\n\n")
-            .map(|line| {
-                line.split('\n')
-                    .flat_map(|num| num.parse::<u32>())
-                    .sum()
-            })
-            .collect::<Vec<u32>>();
+        Ok(Self { calories })
+    }
+}
 
-        lines.sort_by(|a, b| b.cmp(a));
+impl crate::aoc::Day for Day {
+    type Output = u32;
+
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000", 24000)]
+    }
+
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000", 45000)]
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.calories[0])
+    }
 
-        println!("{}", lines[0]);
-        println!("{}", lines.iter().take(3).sum::<u32>());
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.calories.iter().take(3).sum::<u32>())
     }
-}
\ No newline at end of file
+}
@@ -1,12 +1,15 @@
-use std::fs;
+use std::str::FromStr;
 
-#[derive(PartialEq)]
+use crate::aoc::{Context, Error};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 enum Selection {
     Rock = 1,
     Papers = 2,
     Scissors = 3
 }
 
+#[derive(Clone, Copy, Debug)]
 enum Outcome {
     Lose = 0,
     Draw = 3,
@@ -14,41 +17,36 @@ enum Outcome {
 }
 
 impl Selection {
-    fn convert_from_str(selection: &str) -> Self {
+    fn convert_from_str(selection: &str) -> Result<Self, Error> {
         match selection {
-            "A" | "X" => Selection::Rock,
-            "B" | "Y" => Selection::Papers,
-            "C" | "Z" => Selection::Scissors,
-            _ => Selection::Papers
+            "A" | "X" => Ok(Selection::Rock),
+            "B" | "Y" => Ok(Selection::Papers),
+            "C" | "Z" => Ok(Selection::Scissors),
+            _ => Err(Error::Parse(selection.to_string()))
         }
     }
 
-    fn convert_from_opponent_and_outcome(opponent_selection: &Selection, you: &str) -> Selection {
-        match opponent_selection {
-            Selection::Rock => {
-                match you {
-                    "X" => Selection::Scissors, // lose
-                    "Y" => Selection::Rock, // draw
-                    "Z" => Selection::Papers, // win
-                    _ => Selection::Papers
-                }
-            },
-            Selection::Papers => {
-                match you {
-                    "X" => Selection::Rock, // lose
-                    "Y" => Selection::Papers, // draw
-                    "Z" => Selection::Scissors, // win
-                    _ => Selection::Papers
-                }
-            },
-            Selection::Scissors => {
-                match you {
-                    "X" => Selection::Papers, // lose
-                    "Y" => Selection::Scissors, // draw
-                    "Z" => Selection::Rock, // win
-                    _ => Selection::Papers
-                }
-            },
+    fn convert_from_opponent_and_outcome(opponent_selection: &Selection, outcome: &Outcome) -> Selection {
+        match (opponent_selection, outcome) {
+            (selection, Outcome::Draw) => *selection,
+            (Selection::Rock, Outcome::Lose) => Selection::Scissors,
+            (Selection::Rock, Outcome::Win) => Selection::Papers,
+            (Selection::Papers, Outcome::Lose) => Selection::Rock,
+            (Selection::Papers, Outcome::Win) => Selection::Scissors,
+            (Selection::Scissors, Outcome::Lose) => Selection::Papers,
+            (Selection::Scissors, Outcome::Win) => Selection::Rock,
+        }
+    }
+}
+
+impl Outcome {
+    /// The second column read as the outcome of the round, as in part 2.
+    fn convert_from_str(outcome: &str) -> Result<Self, Error> {
+        match outcome {
+            "X" => Ok(Outcome::Lose),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(Error::Parse(outcome.to_string()))
         }
     }
 }
@@ -58,7 +56,7 @@ fn play_game(opponent: &Selection, you: &Selection) -> Outcome {
         return Outcome::Draw;
     }
 
-    if (*you == Selection::Rock && *opponent == Selection::Scissors) 
+    if (*you == Selection::Rock && *opponent == Selection::Scissors)
     || (*you == Selection::Papers && *opponent == Selection::Rock)
     || (*you == Selection::Scissors && *opponent == Selection::Papers) {
         return Outcome::Win;
@@ -67,43 +65,68 @@ fn play_game(opponent: &Selection, you: &Selection) -> Outcome {
     Outcome::Lose
 }
 
-pub struct Day2;
+#[derive(Clone, Debug)]
+struct Round {
+    opponent: Selection,
+    /// The second column as a selection, used by part 1.
+    you: Selection,
+    /// The second column as an outcome, used by part 2.
+    outcome: Outcome,
+}
 
-impl crate::year2022::Day for Day2 {
-    fn date(&self) -> (i32, i32) { (2, 2022) }
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    rounds: Vec<Round>,
+}
 
-    fn run(&self) {
-        let input = fs::read_to_string("src/year_2022/day2/input.txt")
-            .unwrap();
+impl FromStr for Day {
+    type Err = Error;
 
-        let outcomes: Vec<(i32, i32)> = input
-            .split('\n')
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rounds = s.lines()
+            .filter(|line| !line.trim().is_empty())
             .map(|strategy| {
-                if let [opponent, you] = strategy.split(' ').collect::<Vec<&str>>()[..] {
-                    let opponent_selection: Selection = Selection::convert_from_str(opponent);
-                    let your_selection: Selection = Selection::convert_from_str(you);
-                    let your_selection_2: Selection = Selection::convert_from_opponent_and_outcome(&opponent_selection, you);
-
-                    let outcome = play_game(&opponent_selection, &your_selection) as i32;
-                    let outcome_2 = play_game(&opponent_selection, &your_selection_2) as i32;
-                    let result = outcome + (your_selection as i32);
-                    let result_2 = outcome_2 + (your_selection_2 as i32);
-                    return (result, result_2)
+                if let [opponent, you] = strategy.split_whitespace().collect::<Vec<&str>>()[..] {
+                    return Ok(Round {
+                        opponent: Selection::convert_from_str(opponent).within(s, opponent)?,
+                        you: Selection::convert_from_str(you).within(s, you)?,
+                        outcome: Outcome::convert_from_str(you).within(s, you)?,
+                    });
                 }
 
-                (0, 0)
-            }).collect::<Vec<(i32, i32)>>();
+                Err(Error::Parse(strategy.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { rounds })
+    }
+}
+
+impl crate::aoc::Day for Day {
+    type Output = i32;
 
-        /* first  10404*/
-        /* second 10334 */
-        let mut sum_1 = 0;
-        let mut sum_2 = 0;
-        for (outcome_1, outcome_2) in outcomes {
-            sum_1 += outcome_1;
-            sum_2 += outcome_2;
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![("A Y\nB X\nC Z", 15)]
+    }
+
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![("A Y\nB X\nC Z", 12)]
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        let mut sum = 0;
+
+        for round in &self.rounds {
+            sum += play_game(&round.opponent, &round.you) as i32 + round.you as i32;
         }
 
-        println!("{}", sum_1);
-        println!("{}", sum_2);
+        Ok(sum)
     }
-}
\ No newline at end of file
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.rounds.iter().map(|round| {
+            let your_selection = Selection::convert_from_opponent_and_outcome(&round.opponent, &round.outcome);
+            play_game(&round.opponent, &your_selection) as i32 + your_selection as i32
+        }).sum())
+    }
+}
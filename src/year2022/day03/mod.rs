@@ -1,68 +1,74 @@
-use std::fs;
-fn find_common_char(first: &str, second: &str) -> Option<char> {
-    for char in first.chars() {
-        if let Some(index) = second.find(char) {
-            return Some(second.chars().nth(index).unwrap());
-        }
-    }
+use std::str::FromStr;
 
-    None
+fn find_common_char(first: &str, second: &str) -> Option<char> {
+    first.chars().find(|char| second.contains(*char))
 }
 
 fn find_common_char_third(first: &str, second: &str, third: &str) -> Option<char> {
-    for char in first.chars() {
-        if second.find(char).is_some() {
-            if let Some(index) = third.find(char) {
-                return Some(third.chars().nth(index).unwrap());
-            }
-        }
-    }
+    first.chars().find(|char| second.contains(*char) && third.contains(*char))
+}
 
-    None
+fn priority(common_type: char) -> u32 {
+    if common_type.is_uppercase() { common_type as u32 - 65 + 27 } else { common_type as u32 - 96 }
 }
 
-pub struct Day3;
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    rucksacks: Vec<String>,
+}
 
-impl crate::year2022::Day for Day3 {
-    fn date(&self) -> (i32, i32) { (3, 2022) }
+impl FromStr for Day {
+    type Err = crate::aoc::Error;
 
-    fn run(&self) {
-        let input = fs::read_to_string("src/year_2022/day3/input.txt")
-            .unwrap();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            rucksacks: s.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect(),
+        })
+    }
+}
 
-        let first_part_sum = input.split('\n')
-            .map(|rucksack| {
-                let (first_half, second_half) = rucksack.split_at(rucksack.len() / 2);
-                if let Some(common_type) = find_common_char(first_half, second_half) {
-                    return if common_type.is_uppercase() { common_type as u32 - 65 + 27 } else { common_type as u32 - 96 };
-                }
+impl crate::aoc::Day for Day {
+    type Output = u32;
 
-                0
-            }).collect::<Vec<u32>>()
-            .iter()
-            .sum::<u32>();
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![("vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw", 157)]
+    }
 
-        let mut group = vec![];
-        let second_part_sum = input.split('\n')
-            .map(|rucksack| {
-                group.push(rucksack);
-                if group.len() == 3 {
-                    if let [first, second, third] = group[..] {
-                        if let Some(common_type) = find_common_char_third(first, second, third) {
-                            let result = if common_type.is_uppercase() { common_type as u32 - 65 + 27 } else { common_type as u32 - 96 };
-                            group.clear();
-                            return result;
-                        }
-                    }
-                    0
-                } else {
-                    0
-                }
-            }).collect::<Vec<u32>>()
-            .iter()
-            .sum::<u32>();
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![("vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw", 70)]
+    }
 
-        println!("Sum: {}", first_part_sum);
-        println!("Sum: {}", second_part_sum);
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.rucksacks.iter()
+            .filter_map(|rucksack| {
+                let (first_half, second_half) = rucksack.split_at(rucksack.len() / 2);
+                find_common_char(first_half, second_half)
+            })
+            .map(priority)
+            .sum())
     }
-}
\ No newline at end of file
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.rucksacks.chunks(3)
+            .filter_map(|group| match group {
+                [first, second, third] => find_common_char_third(first, second, third),
+                _ => None
+            })
+            .map(priority)
+            .sum())
+    }
+}
@@ -1,11 +1,14 @@
-use std::fs;
 use std::ops::Range;
-fn range_from_str(target: &str) -> Range<u32> {
+use std::str::FromStr;
+
+use crate::aoc::Error;
+
+fn range_from_str(target: &str) -> Result<Range<u32>, Error> {
     if let [lower, upper] = target.split('-').collect::<Vec<&str>>()[..] {
-        return lower.parse::<u32>().unwrap()..upper.trim().parse::<u32>().unwrap()
+        return Ok(lower.trim().parse::<u32>()?..upper.trim().parse::<u32>()?)
     }
 
-    0..0
+    Err(Error::Parse(target.to_string()))
 }
 
 fn range_touches(a: &Range<u32>, b: &Range<u32>) -> bool {
@@ -18,50 +21,50 @@ fn range_contains(a: &Range<u32>, b: &Range<u32>) -> bool {
     s.contains(&b.start) && s.contains(&b.end)
 }
 
-pub struct Day4;
-
-impl crate::year2022::Day for Day4 {
-    fn date(&self) -> (i32, i32) {
-        (4, 2022)
-    }
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    assignment_pairs: Vec<(Range<u32>, Range<u32>)>,
+}
 
-    fn run(&self) {
-        let input = fs::read_to_string("src/year2022/day4/input.txt")
-            .unwrap();
+impl FromStr for Day {
+    type Err = Error;
 
-        let assignment_pairs_first = input.split('\n')
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let assignment_pairs = s.lines()
+            .filter(|line| !line.trim().is_empty())
             .map(|assignment_pair| {
                 if let [first, second] = assignment_pair.split(',').collect::<Vec<&str>>()[..] {
-                    let r_1 = range_from_str(first);
-                    let r_2 = range_from_str(second);
-
-                    return if range_contains(&r_1, &r_2) || range_contains(&r_2, &r_1) {
-                        1
-                    } else {
-                        0
-                    }
+                    return Ok((range_from_str(first)?, range_from_str(second)?));
                 }
 
-                0
-            }).sum::<u32>();
+                Err(Error::Parse(assignment_pair.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-        let assignment_pairs_second = input.split('\n')
-            .map(|assignment_pair| {
-                if let [first, second] = assignment_pair.split(',').collect::<Vec<&str>>()[..] {
-                    let r_1 = range_from_str(first);
-                    let r_2 = range_from_str(second);
+        Ok(Self { assignment_pairs })
+    }
+}
 
-                    return if range_touches(&r_1, &r_2) || range_contains(&r_2, &r_1) {
-                        1
-                    } else {
-                        0
-                    }
-                }
+impl crate::aoc::Day for Day {
+    type Output = u32;
+
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8", 2)]
+    }
 
-                0
-            }).sum::<u32>();
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8", 4)]
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.assignment_pairs.iter()
+            .filter(|(r_1, r_2)| range_contains(r_1, r_2) || range_contains(r_2, r_1))
+            .count() as u32)
+    }
 
-        println!("Amount overlaps: {}", assignment_pairs_first);
-        println!("Amount touches: {}", assignment_pairs_second);
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.assignment_pairs.iter()
+            .filter(|(r_1, r_2)| range_touches(r_1, r_2) || range_contains(r_2, r_1))
+            .count() as u32)
     }
-}
\ No newline at end of file
+}
@@ -1,116 +1,119 @@
-use std::fs;
+use std::str::FromStr;
 
-#[derive(Debug)]
+use crate::aoc::Error;
+use crate::utils::parse;
+
+#[derive(Debug, Clone)]
 struct Move {
-    amount: u32,
-    from: u32,
-    to: u32
+    amount: usize,
+    from: usize,
+    to: usize
 }
 
-fn extract_moves(input: &str) -> Vec<Move> {
-    let mut moves: Vec<Move> = Vec::new();
-    let second_half = input.split("\r\n\r\n").collect::<Vec<&str>>()[1].split('\n').collect::<Vec<&str>>();
-
-    for move_str in second_half {
-        if let [_, amount, _, from, _, to] = move_str.split(' ').collect::<Vec<&str>>()[..] {
-            moves.push(Move {
-                amount: amount.parse::<u32>().unwrap(),
-                from: from.parse::<u32>().unwrap(),
-                to: to.trim().parse::<u32>().unwrap(),
+/// Moves with zero based stack indices, `input` is the whole puzzle input for error locations.
+fn extract_moves(input: &str, moves: &str, amount_stacks: usize) -> Result<Vec<Move>, Error> {
+    let mut result: Vec<Move> = Vec::new();
+    let stack = |number: &str| -> Result<usize, Error> {
+        match parse::value::<usize>(input, number)? {
+            number @ 1.. if number <= amount_stacks => Ok(number - 1),
+            _ => Err(Error::at(format!("expected a stack from 1 to {amount_stacks}"), input, number)),
+        }
+    };
+
+    for move_str in moves.lines() {
+        if let [_, amount, _, from, _, to] = move_str.split_whitespace().collect::<Vec<&str>>()[..] {
+            result.push(Move {
+                amount: parse::value(input, amount)?,
+                from: stack(from)?,
+                to: stack(to)?,
             })
         }
     }
 
-    moves
+    Ok(result)
 }
 
-fn extract_stack(input: &str) -> Vec<Vec<char>> {
-    let first_half = input.split("\r\n\r\n").collect::<Vec<&str>>()[0];
-    let reversed = first_half.chars().rev().collect::<String>();
-
-    let lines = reversed.split("\n\r").collect::<Vec<&str>>();
-    let amount_stacks = lines[0].split(' ').collect::<Vec<&str>>()[0]
-        .parse::<usize>()
-        .unwrap();
-
-    let mut stacks: Vec<Vec<char>> = Vec::with_capacity(amount_stacks);
-    for _ in 0..amount_stacks {
-        stacks.push(Vec::new());
-    }
-
-    for line in &lines[1..] {
-        let mut iterator = line.chars();
-        let mut counter: i32 = (amount_stacks - 1) as i32;
+fn extract_stack(drawing: &str) -> Result<Vec<Vec<char>>, Error> {
+    let mut lines = drawing.lines().rev();
+    let amount_stacks = lines.next()
+        .and_then(|numbers| numbers.split_whitespace().last())
+        .ok_or(Error::Parse(drawing.to_string()))?
+        .parse::<usize>()?;
 
-        while let Some(_) = iterator.next() {
-            let letter = iterator.next().unwrap();
-            let _ = iterator.next(); // closing bracket
-            let _ = iterator.next(); // space
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); amount_stacks];
 
+    for line in lines {
+        // every crate is drawn as "[X] ", so the letter of stack i sits at 4 * i + 1
+        for (i, letter) in line.chars().skip(1).step_by(4).enumerate().take(amount_stacks) {
             if letter != ' ' {
-                stacks[counter as usize].push(letter);
-            }
-            counter -= 1;
-
-            if counter < 0 {
-                counter = (amount_stacks - 1) as i32;
+                stacks[i].push(letter);
             }
         }
     }
 
-    stacks
+    Ok(stacks)
 }
 
-pub struct Day5;
+fn top_letters(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
 
-impl crate::year2022::Day for Day5 {
-    fn date(&self) -> (i32, i32) {
-        (5, 2022)
-    }
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
 
-    fn run(&self) {
-        let input = fs::read_to_string("src/year_2022/day5/input.txt").unwrap();
+impl FromStr for Day {
+    type Err = Error;
 
-        let mut stack_representation: Vec<Vec<char>> = extract_stack(&input);
-        let move_list: Vec<Move> = extract_moves(&input);
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [drawing, moves] = parse::sections(s)[..] else {
+            return Err(Error::at("expected the stacks and the moves separated by an empty line", s, s.lines().next().unwrap_or(s)));
+        };
+        let stacks = extract_stack(drawing)?;
 
-        for m in &move_list {
-            for _ in 0..m.amount {
-                let letter = stack_representation[(m.from - 1) as usize].pop().unwrap();
-                stack_representation[(m.to - 1) as usize].push(letter);
-            }
-        }
+        Ok(Self {
+            moves: extract_moves(s, moves, stacks.len())?,
+            stacks,
+        })
+    }
+}
 
-        let mut final_word: String = String::from("");
-        for stack in &stack_representation {
-            let top_letter = stack.last().unwrap();
-            final_word += top_letter.to_string().as_str();
-        }
+impl crate::aoc::Day for Day {
+    type Output = String;
 
-        println!("First part: {}", final_word);
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2", String::from("CMZ"))]
+    }
 
-        let mut stack_representation: Vec<Vec<char>> = extract_stack(&input);
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2", String::from("MCD"))]
+    }
 
-        for m in &move_list {
-            let mut sub_stack = String::from("");
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        let mut stack_representation = self.stacks.clone();
+
+        for m in &self.moves {
             for _ in 0..m.amount {
-                let letter = stack_representation[(m.from - 1) as usize].pop().unwrap();
-                sub_stack += letter.to_string().as_str();
+                if let Some(letter) = stack_representation[m.from].pop() {
+                    stack_representation[m.to].push(letter);
+                }
             }
+        }
 
-            let rev_sub_stack = sub_stack.chars().rev().collect::<String>();
+        Ok(top_letters(&stack_representation))
+    }
 
-            for crate_str in rev_sub_stack.chars() {
-                stack_representation[(m.to - 1) as usize].push(crate_str);
-            }
-        }
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let mut stack_representation = self.stacks.clone();
 
-        let mut final_word: String = String::from("");
-        for stack in &stack_representation {
-            let top_letter = stack.last().unwrap();
-            final_word += top_letter.to_string().as_str();
+        for m in &self.moves {
+            let from = &mut stack_representation[m.from];
+            let sub_stack = from.split_off(from.len().saturating_sub(m.amount));
+            stack_representation[m.to].extend(sub_stack);
         }
 
-        println!("Second part: {}", final_word);
+        Ok(top_letters(&stack_representation))
     }
-}
\ No newline at end of file
+}
@@ -1,28 +1,28 @@
-use std::fs;
+use std::str::FromStr;
 
+use crate::aoc::Error;
 
-fn calculation(unique_chars: usize) {
-    let input = fs::read_to_string("src/year_2022/day6/input.txt").unwrap();
+fn calculation(input: &str, unique_chars: usize) -> Result<usize, Error> {
     let mut lower_limit = 0;
     let mut upper_limit = unique_chars;
 
-    while upper_limit < input.len() {
+    while upper_limit <= input.len() {
         let s = &input[lower_limit..upper_limit];
-        if all_different_bitwise(s, unique_chars) {
-            break;
+        if all_different_bitwise(s) {
+            return Ok(upper_limit);
         }
 
         lower_limit += 1;
         upper_limit += 1;
     }
 
-    println!("{}", upper_limit);
+    Err(Error::NoSolutionFound)
 }
 
-fn all_different_bitwise(sequence: &str, check_amount: usize) -> bool {
+fn all_different_bitwise(sequence: &str) -> bool {
     let mut data: u64 = 0b0;
-    for i in 0..check_amount {
-        let target = sequence.chars().nth(i).unwrap() as usize - 65;
+    for char in sequence.bytes() {
+        let target = (char - b'A') as usize;
 
         // is it set at this position?
         if (data & 1 << target) != 0 { // if there is a 1 already
@@ -35,15 +35,45 @@ fn all_different_bitwise(sequence: &str, check_amount: usize) -> bool {
     true
 }
 
-pub struct Day6;
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    datastream: String,
+}
+
+impl FromStr for Day {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { datastream: s.trim().to_string() })
+    }
+}
+
+impl crate::aoc::Day for Day {
+    type Output = usize;
 
-impl crate::year2022::Day for Day6 {
-    fn date(&self) -> (i32, i32) {
-        (6, 2022)
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ]
     }
 
-    fn run(&self) {
-        calculation(4);
-        calculation(14);
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ]
     }
-}
\ No newline at end of file
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(calculation(&self.datastream, 4)?)
+    }
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(calculation(&self.datastream, 14)?)
+    }
+}
@@ -1,20 +1,23 @@
 use std::collections::HashMap;
-use std::fs;
+use std::str::FromStr;
 
-#[derive(Debug)]
+use crate::aoc::Error;
+use crate::utils::parse;
+
+#[derive(Debug, Clone)]
 struct File {
     _name: String,
     size: i32
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct FileSystem {
     current_directory_stack: Vec<String>,
     root_directory: Directory,
 }
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Directory {
     name: String,
     files: Vec<File>,
@@ -50,19 +53,26 @@ impl Directory {
 impl FileSystem {
     pub fn new() -> Self {
         FileSystem {
-            current_directory_stack: vec![],
+            current_directory_stack: vec![String::from("/")],
             root_directory: Directory::from("/"),
         }
     }
 
-    pub fn add_or_ignore_directory(&mut self, directory: &str) {
+    pub fn add_or_ignore_directory(&mut self, directory: &str) -> Result<(), String> {
+        self.current_directory()?.try_insert_directory(directory, Directory::from(directory));
+        Ok(())
+    }
+
+    /// The directory at the top of the stack, which starts at `/`. Fails for directories that were never listed.
+    fn current_directory(&mut self) -> Result<&mut Directory, String> {
         let mut directory_pointer: &mut Directory = &mut self.root_directory;
 
-        for path in &self.current_directory_stack[1..] {
-            directory_pointer = directory_pointer.directories.get_mut(path.as_str()).unwrap();
+        for path in self.current_directory_stack.iter().skip(1) {
+            directory_pointer = directory_pointer.directories.get_mut(path.as_str())
+                .ok_or_else(|| format!("directory {path} has not been listed"))?;
         }
 
-        directory_pointer.try_insert_directory(directory, Directory::from(directory));
+        Ok(directory_pointer)
     }
 
     pub fn directories_info(root_directory: &Directory) -> Vec<DirectoryInfo> {
@@ -97,14 +107,9 @@ impl FileSystem {
     }
 
 
-    pub fn add_file(&mut self, file_name: &str, size: i32) {
-        let mut directory_pointer: &mut Directory = &mut self.root_directory;
-
-        for path in &self.current_directory_stack[1..] {
-            directory_pointer = directory_pointer.directories.get_mut(path.as_str()).unwrap();
-        }
-
-        directory_pointer.insert_file(file_name, size);
+    pub fn add_file(&mut self, file_name: &str, size: i32) -> Result<(), String> {
+        self.current_directory()?.insert_file(file_name, size);
+        Ok(())
     }
 
     pub fn change_directory(&mut self, directory: &str) {
@@ -115,7 +120,10 @@ impl FileSystem {
         }
 
         if directory == ".." {
-            self.current_directory_stack.pop();
+            // `cd ..` in `/` stays in `/`
+            if self.current_directory_stack.len() > 1 {
+                self.current_directory_stack.pop();
+            }
             return;
         }
 
@@ -123,73 +131,77 @@ impl FileSystem {
     }
 }
 
-pub struct Day7;
-
-impl crate::year2022::Day for Day7 {
-    fn date(&self) -> (i32, i32) { (7, 2022) }
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    directories: Vec<DirectoryInfo>,
+}
 
-    fn run(&self) {
-        // let input = fs::read_to_string("src/year_2022/day7/testing.txt").unwrap();
-        let input = fs::read_to_string("src/year_2022/day7/input.txt").unwrap();
+impl FromStr for Day {
+    type Err = Error;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut file_system: FileSystem = FileSystem::new();
 
-        for line in input.split('\n') {
+        for line in s.lines() {
             if line.starts_with('$') {
-                let split_vec = &line.split(' ').collect::<Vec<&str>>()[..];
-                if let [_, _, argument] = split_vec { // cd command
-                    let argument = argument.trim();
-                    file_system.change_directory(argument);
-                } else if let [_, _] = split_vec { // ls command
-
+                if let [_, "cd", argument] = &line.split(' ').collect::<Vec<&str>>()[..] { // cd command
+                    file_system.change_directory(argument.trim());
                 }
 
                 continue;
             }
 
-            let line_split = line.split(' ').collect::<Vec<&str>>();
-
-            if line_split[0] == "dir" { // directory
-                file_system.add_or_ignore_directory(line_split[1].trim());
-                continue;
-            }
-
-            if line_split[0].parse::<i32>().is_ok() { // file
-                file_system.add_file(line_split[1].trim(), line_split[0].parse::<i32>().unwrap());
-                continue;
+            match line.trim().split_once(' ') {
+                Some(("dir", directory)) => {
+                    file_system.add_or_ignore_directory(directory.trim()).map_err(|message| Error::at(message, s, line))?;
+                }
+                Some((size, file_name)) => {
+                    let size = parse::value::<i32>(s, size)?;
+                    file_system.add_file(file_name.trim(), size).map_err(|message| Error::at(message, s, line))?;
+                }
+                None if line.trim().is_empty() => {}
+                None => return Err(Error::at("expected \"dir <name>\" or \"<size> <name>\"", s, line)),
             }
         }
 
-        let directories: Vec<DirectoryInfo> = FileSystem::directories_info(&file_system.root_directory);
+        Ok(Self {
+            directories: FileSystem::directories_info(&file_system.root_directory),
+        })
+    }
+}
 
-        let mut sum = 0;
+impl crate::aoc::Day for Day {
+    type Output = i32;
 
-        for directory in &directories {
-            if directory.size <= 100000 {
-                sum += directory.size;
-            }
-        }
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("testing.txt"), 95437)]
+    }
+
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("testing.txt"), 24933642)]
+    }
 
-        println!("Part one: {}", sum);
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.directories.iter()
+            .filter(|directory| directory.size <= 100000)
+            .map(|directory| directory.size)
+            .sum())
+    }
 
-        // part 2
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
         let total_disk_space = 70000000;
         let target_unused_space = 30000000;
 
-        let root = directories.last().unwrap();
+        let root = self.directories.last().ok_or(Error::NoSolutionFound)?;
 
         let unused_space = total_disk_space - root.size;
         let still_required_space = target_unused_space - unused_space;
 
-        let mut candidates: Vec<&DirectoryInfo> = vec![];
+        let result = self.directories.iter()
+            .filter(|directory| directory.size >= still_required_space)
+            .min_by_key(|directory| directory.size)
+            .ok_or(Error::NoSolutionFound)?;
 
-        for directory in &directories {
-            if directory.size >= still_required_space {
-                candidates.push(directory);
-            }
-        }
-
-        let result = candidates.iter().min_by_key(|can| can.size).unwrap();
-        println!("Part two: {:?}", result);
+        Ok(result.size)
     }
-}
\ No newline at end of file
+}
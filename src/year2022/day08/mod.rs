@@ -1,26 +1,29 @@
-use std::fs;
 use std::ops::Index;
+use std::str::FromStr;
 
+use crate::aoc::Error;
+
+#[derive(Default, Clone, Debug)]
 struct Grid {
     data: Vec<Vec<u32>>
 }
 
 impl Grid {
-    pub fn from(input: &Vec<&str>) -> Self {
+    pub fn from(input: &[&str]) -> Result<Self, Error> {
         let mut data: Vec<Vec<u32>> = vec![];
 
         for line in input {
             let mut row: Vec<u32> = vec![];
             for char in line.chars() {
-                let digit = char.to_digit(10).unwrap();
+                let digit = char.to_digit(10).ok_or(Error::Parse(char.to_string()))?;
                 row.push(digit);
             }
             data.push(row);
         }
 
-        Grid {
+        Ok(Grid {
             data
-        }
+        })
     }
 
     pub fn is_visible(&self, x: usize, y: usize) -> bool {
@@ -125,20 +128,39 @@ impl Index<(usize, usize)> for Grid {
     }
 }
 
-pub struct Day8;
-impl crate::year2022::Day for Day8 {
-    fn date(&self) -> (i32, i32) { (8, 2022) }
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    grid: Grid,
+}
 
-    fn run(&self) {
-        let input = fs::read_to_string("src/year_2022/day8/input.txt").unwrap();
+impl FromStr for Day {
+    type Err = Error;
 
-        let lines = input.split('\n').map(|line| {
-            line.trim()
-        }).collect::<Vec<&str>>();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>();
 
-        let grid: Grid = Grid::from(&lines);
+        Ok(Self { grid: Grid::from(&lines)? })
+    }
+}
+
+impl crate::aoc::Day for Day {
+    type Output = usize;
 
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("text.txt"), 21)]
+    }
+
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("text.txt"), 8)]
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        let grid = &self.grid;
         let mut counter = 0;
+
         for x in 0..grid.width() {
             for y in 0..grid.height() {
                 if grid.is_visible(x, y) {
@@ -147,11 +169,14 @@ impl crate::year2022::Day for Day8 {
             }
         }
 
-        println!("Part one: {}", counter);
+        Ok(counter)
+    }
 
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let grid = &self.grid;
         let mut highest_scenic_score = 0;
 
-        for x in 1..grid.width() - 1{
+        for x in 1..grid.width() - 1 {
             for y in 1..grid.height() - 1 {
                 let current_score = grid.scenic_score(x, y);
                 if current_score > highest_scenic_score {
@@ -160,6 +185,6 @@ impl crate::year2022::Day for Day8 {
             }
         }
 
-        println!("Part two: {}", highest_scenic_score);
+        Ok(highest_scenic_score)
     }
-}
\ No newline at end of file
+}
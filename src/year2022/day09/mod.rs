@@ -1,5 +1,7 @@
 use std::collections::HashSet;
-use std::fs;
+use std::str::FromStr;
+
+use crate::aoc::Error;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Position {
@@ -73,8 +75,8 @@ impl Rope {
         }
     }
 
-    pub fn run_movements(&mut self, movements: &Vec<Movement>) -> Vec<Position> {
-        let mut tail_positions = vec![*self.positions.last().unwrap()];
+    pub fn run_movements(&mut self, movements: &[Movement]) -> Vec<Position> {
+        let mut tail_positions = self.positions.last().copied().into_iter().collect::<Vec<_>>();
 
         for movement in movements {
             for _ in 0..movement.steps {
@@ -86,19 +88,20 @@ impl Rope {
                 }
 
                 self.traverse_tails();
-                tail_positions
-                    .push(*self.positions.last().unwrap());
+                tail_positions.extend(self.positions.last());
             }
         }
         tail_positions
     }
 }
 
+#[derive(Debug, Clone)]
 struct Movement {
     direction: Direction,
     steps: usize,
 }
 
+#[derive(Debug, Clone)]
 enum Direction {
     Left,
     Right,
@@ -106,17 +109,25 @@ enum Direction {
     Down,
 }
 
+fn unique_tail_positions(movements: &[Movement], tail_size: usize) -> usize {
+    let mut state = Rope::new(tail_size);
+    let positions = state.run_movements(movements);
+    let unique_positions: HashSet<Position> = positions.into_iter().collect();
 
-pub struct Day9;
+    unique_positions.len()
+}
 
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    moves: Vec<Movement>,
+}
 
-impl crate::year2022::Day for Day9 {
-    fn date(&self) -> (i32, i32) { (9, 2022) }
+impl FromStr for Day {
+    type Err = Error;
 
-    fn run(&self) {
-        let moves = fs::read_to_string("src/year_2022/day9/input.txt")
-            .unwrap()
-            .lines()
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let moves = s.lines()
+            .filter(|line| !line.trim().is_empty())
             .map(|line| {
                 if let [dir, unit] = line.split(' ').collect::<Vec<&str>>()[..] {
                     let direction = match dir {
@@ -124,26 +135,38 @@ impl crate::year2022::Day for Day9 {
                         "R" => Direction::Right,
                         "U" => Direction::Up,
                         "D" => Direction::Down,
-                        _ => panic!("Direction not found"),
+                        _ => return Err(Error::Parse(dir.to_string())),
                     };
 
-                    return Movement {
+                    return Ok(Movement {
                         direction,
-                        steps: unit.trim().parse::<usize>().unwrap(),
-                    };
+                        steps: unit.trim().parse::<usize>()?,
+                    });
                 }
 
-                panic!("cant parse");
-            }).collect::<Vec<Movement>>();
+                Err(Error::Parse(line.to_string()))
+            }).collect::<Result<Vec<Movement>, _>>()?;
+
+        Ok(Self { moves })
+    }
+}
+
+impl crate::aoc::Day for Day {
+    type Output = usize;
+
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 13)]
+    }
 
-        let mut state = Rope::new(2);
-        let positions = state.run_movements(&moves);
-        let unique_positions: HashSet<Position> = positions.into_iter().collect();
-        println!("Part one: {}", unique_positions.len());
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 1), ("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20", 36)]
+    }
 
-        let mut state = Rope::new(10);
-        let positions = state.run_movements(&moves);
-        let unique_positions: HashSet<Position> = positions.into_iter().collect();
-        println!("Part two: {}", unique_positions.len());
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(unique_tail_positions(&self.moves, 2))
     }
-}
\ No newline at end of file
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(unique_tail_positions(&self.moves, 10))
+    }
+}
@@ -1,66 +1,106 @@
-use std::fs;
+use std::str::FromStr;
 
-pub struct Day10;
+use crate::aoc::Error;
+use crate::utils::parse;
 
-impl crate::year2022::Day for Day10 {
-    fn date(&self) -> (i32, i32) { (10, 2022) }
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Noop,
+    Addx(i32),
+}
 
-    fn run(&self) {
-        // let input = fs::read_to_string("src/year_2022/day10/test.txt").unwrap();
-        let input = fs::read_to_string("src/year_2022/day10/input.txt").unwrap();
-        let vec = input.split('\n').collect::<Vec<&str>>();
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    program: Vec<Instruction>,
+}
 
-        let (_, signal_strength) = run_until(&vec, None);
-        println!("Part one: {}", signal_strength);
+impl FromStr for Day {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            program: s.lines()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .map(|line| match line.split_whitespace().collect::<Vec<&str>>()[..] {
+                    ["noop"] => Ok(Instruction::Noop),
+                    ["addx", unit] => Ok(Instruction::Addx(parse::value(s, unit)?)),
+                    _ => Err(Error::at("expected \"noop\" or \"addx <value>\"", s, line)),
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
 
-        println!("Part two:");
+impl crate::aoc::Day for Day {
+    type Output = String;
+
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), String::from("13140"))]
+    }
+
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), String::from("
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."))]
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        let (_, signal_strength) = run_until(&self.program, None);
+        Ok(signal_strength.to_string())
+    }
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let mut screen = String::new();
 
         for y in 0..6 {
-            let mut row: String = String::from("");
+            screen.push('\n');
             for x in 0..40 {
                 let cycle = y * 40 + x;
-                let c = if (x - run_until(&vec, Some(cycle)).0).abs() <= 1 { "#" } else { "." };
-                row += c;
+                screen.push(if (x - run_until(&self.program, Some(cycle)).0).abs() <= 1 { '#' } else { '.' });
             }
-
-            println!("{row}");
         }
+
+        Ok(screen)
     }
 }
 
-fn run_until(vec: &Vec<&str>, limit: Option<i32>) -> (i32, i32) {
+fn run_until(program: &[Instruction], limit: Option<i32>) -> (i32, i32) {
     let target_cycle: Vec<i32> = (20..=220).step_by(40).collect::<Vec<i32>>();
     let mut cycles = 0;
     let mut register_x = 1;
     let mut signal_strength = 0;
 
-    for line in vec {
-        match line.split(' ').map(|p| p.trim()).collect::<Vec<&str>>()[..] {
-            ["noop"] => {
+    for instruction in program {
+        match instruction {
+            Instruction::Noop => {
                 cycles += 1;
 
                 signal_strength = increase_st(signal_strength, check_cycle(&target_cycle, cycles, register_x));
 
-                if limit.is_some() && cycles >= limit.unwrap() {
+                if limit.is_some_and(|limit| cycles >= limit) {
                     break;
                 }
             },
-            ["addx", unit] => {
+            Instruction::Addx(unit) => {
                 cycles += 1;
 
                 signal_strength = increase_st(signal_strength, check_cycle(&target_cycle, cycles, register_x));
-                if limit.is_some() && cycles >= limit.unwrap() {
+                if limit.is_some_and(|limit| cycles >= limit) {
                     break;
                 }
                 cycles += 1;
                 signal_strength = increase_st(signal_strength, check_cycle(&target_cycle, cycles, register_x));
 
-                register_x += unit.trim().parse::<i32>().unwrap();
-                if limit.is_some() && cycles >= limit.unwrap() {
+                register_x += unit;
+                if limit.is_some_and(|limit| cycles >= limit) {
                     break;
                 }
             },
-            _ => { }
         }
     }
 
@@ -1,43 +1,66 @@
-use std::fs;
 use std::str::FromStr;
 
-#[derive(Debug, Clone)]
-struct Operation {
-    operation: String
+use crate::aoc::{Context, Error};
+use crate::utils::parse;
+
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    Old,
+    Value(u128),
+}
+
+impl Operand {
+    fn value(self, old: u128) -> u128 {
+        match self {
+            Operand::Old => old,
+            Operand::Value(value) => value,
+        }
+    }
+}
+
+/// `new = old <op> <operand>`, both operations commute so `3 * old` is stored as `Mul(Value(3))`.
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add(Operand),
+    Mul(Operand),
 }
 
 impl Operation {
     pub fn execute(&self, old: u128) -> u128 {
-        return match self.operation.split("new = ").collect::<Vec<&str>>()[..][1].split(' ').collect::<Vec<&str>>()[..] {
-            ["old", operation, "old"] => {
-                let operation = operation.trim();
-
-                match operation {
-                    "*" => old * old,
-                    "+" => old + old,
-                    _ => panic!("Unexpected operation")
-                }
-            },
-            ["old", operation, value] => {
-                let operation = operation.trim();
-                let value = value.trim().parse::<u128>().unwrap();
-                match operation {
-                    "*" => old * value,
-                    "+" => old + value,
-                    _ => panic!("Unexpected operation")
-                }
-            },
-            [value, operation, "old"] => {
-                let operation = operation.trim();
-                let value = value.trim().parse::<u128>().unwrap();
-                match operation {
-                    "*" => value * old,
-                    "+" => value + old,
-                    _ => panic!("Unexpected operation")
-                }
-            },
-            _ => panic!("Unexpected operation")
+        match self {
+            Operation::Add(operand) => old + operand.value(old),
+            Operation::Mul(operand) => old * operand.value(old),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expression = s.trim().strip_prefix("new =").ok_or_else(|| Error::at("expected \"new = ...\"", s, s))?;
+        let operand = |token: &str| -> Result<Operand, Error> {
+            match token {
+                "old" => Ok(Operand::Old),
+                value => Ok(Operand::Value(parse::value(s, value)?)),
+            }
         };
+
+        match expression.split_whitespace().collect::<Vec<_>>()[..] {
+            [left, operator, right] => {
+                let operand = match (operand(left)?, operand(right)?) {
+                    (Operand::Old, other) | (other, Operand::Old) => other,
+                    _ => return Err(Error::at("expected old on one side", s, expression.trim())),
+                };
+
+                match operator {
+                    "+" => Ok(Operation::Add(operand)),
+                    "*" => Ok(Operation::Mul(operand)),
+                    _ => Err(Error::at(format!("unknown operator {operator}"), s, operator)),
+                }
+            }
+            _ => Err(Error::at("expected \"old <+|*> <old|number>\"", s, expression.trim())),
+        }
     }
 }
 
@@ -57,54 +80,24 @@ impl Monkey {
 }
 
 impl FromStr for Monkey {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(target: &str) -> Result<Self, Self::Err> {
-        let mut starting_items = vec![];
-        let mut operation = Operation { operation: String::from("") };
-        let mut test_divisible_by: u128 = 0;
-        let mut test_divisible_result: [usize; 2] = [0, 0];
-
-        let lines = target.lines().collect::<Vec<&str>>();
-        for (i, line) in lines.iter().enumerate() {
-            let value: &str = line.split(':').collect::<Vec<&str>>()[1].trim();
-            if i == 1 { // starting items
-                let matching = &value.split(',').collect::<Vec<&str>>()[..];
-                match matching {
-                    [worry_level] => {
-                        starting_items.push((*worry_level).trim().parse::<u128>().unwrap());
-                    },
-                    _ => {
-                        for worry_level in matching {
-                            starting_items.push((*worry_level).trim().parse::<u128>().unwrap());
-                        }
-                    }
-                }
-            } else if i == 2 { // operation
-                operation.operation = value.trim().to_string();
-            } else if i == 3 {
-                match value.split(' ').collect::<Vec<&str>>()[..] {
-                    ["divisible", "by", number] => {
-                        test_divisible_by = number.trim().parse::<u128>().unwrap();
-                    },
-                    _ => panic!("Divisible by")
-                }
-            } else if i == 4 {
-                match value.split(' ').collect::<Vec<&str>>()[..] {
-                    ["throw", "to", "monkey", monkey_index] => {
-                        test_divisible_result[0] = monkey_index.trim().parse::<usize>().unwrap();
-                    },
-                    _ => panic!("test divisible result 0")
-                }
-            } else if i == 5 {
-                match value.split(' ').collect::<Vec<&str>>()[..] {
-                    ["throw", "to", "monkey", monkey_index] => {
-                        test_divisible_result[1] = monkey_index.trim().parse::<usize>().unwrap();
-                    },
-                    _ => panic!("test divisible result 1")
-                }
+        let starting_items = parse::field_str(target, "Starting items")?;
+        let starting_items = parse::list::<u128>(starting_items, ',').within(target, starting_items)?;
+        let operation = parse::field_str(target, "Operation")?;
+        let operation = Operation::from_str(operation).within(target, operation)?;
+
+        let single_number = |label: &str| -> Result<u128, Error> {
+            let field = parse::field_str(target, label)?;
+            match parse::unsigned::<u128>(field).within(target, field)?[..] {
+                [number] => Ok(number),
+                _ => Err(Error::at("expected a single number", target, field)),
             }
-        }
+        };
+
+        let test_divisible_by = single_number("Test")?;
+        let test_divisible_result = [single_number("If true")? as usize, single_number("If false")? as usize];
 
         Ok(Monkey {
             starting_items,
@@ -116,74 +109,79 @@ impl FromStr for Monkey {
     }
 }
 
-pub struct Day11;
-impl crate::year2022::Day for Day11 {
-    fn date(&self) -> (i32, i32) { (11, 2022) }
+fn monkey_business(mut monkeys: Vec<Monkey>, rounds: usize, relief: impl Fn(u128) -> u128) -> u128 {
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let monkey = monkeys[i].clone();
+
+            for starting_item in &monkey.starting_items {
+                let worry_level = relief(monkey.operation.execute(*starting_item));
+                let new_monkey_idx = if worry_level.is_multiple_of(monkey.test_divisible_by) {
+                    monkey.test_divisible_result[0]
+                } else {
+                    monkey.test_divisible_result[1]
+                };
+
+                monkeys[i].increase_inspection(1);
+                monkeys[new_monkey_idx].starting_items.push(worry_level);
+            }
+
+            monkeys[i].starting_items.clear();
+        }
+    }
+
+    monkeys.sort_by(|a, b| {
+        b.inspections.cmp(&a.inspections)
+    });
 
-    fn run(&self) {
-        let source_monkeys: Vec<Monkey> = fs::read_to_string("src/year_2022/day11/input.txt").unwrap()
-            .split("\r\n\r\n")
-            .map(Monkey::from_str)
-            .collect::<Result<_, _>>().expect("parsing");
+    monkeys[0].inspections * monkeys[1].inspections
+}
 
-        let mut monkeys: Vec<Monkey> = source_monkeys.clone();
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    monkeys: Vec<Monkey>,
+}
 
-        for _ in 0..20 {
-            for i in 0..monkeys.len() {
-                let monkey = monkeys[i].clone();
+impl FromStr for Day {
+    type Err = Error;
 
-                for starting_item in &monkey.starting_items {
-                    let worry_level = (monkey.operation.execute(*starting_item) as f64 / 3.0) as u128;
-                    let new_monkey_idx = if worry_level % monkey.test_divisible_by == 0 {
-                        monkey.test_divisible_result[0]
-                    } else {
-                        monkey.test_divisible_result[1]
-                    };
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sections = parse::sections(s);
+        let monkeys = sections.iter()
+            .map(|monkey| Monkey::from_str(monkey).within(s, monkey))
+            .collect::<Result<Vec<_>, _>>()?;
 
-                    monkeys[i].increase_inspection(1);
-                    monkeys[new_monkey_idx].starting_items.push(worry_level);
+        for (section, monkey) in sections.iter().zip(&monkeys) {
+            for (label, target) in ["If true", "If false"].into_iter().zip(monkey.test_divisible_result) {
+                if target >= monkeys.len() {
+                    let field = parse::field_str(section, label)?;
+                    return Err(Error::at(format!("there is no monkey {target}"), section, field).within(s, section));
                 }
-
-                monkeys[i].starting_items.clear();
             }
         }
 
-        monkeys.sort_by(|a, b| {
-            b.inspections.cmp(&a.inspections)
-        });
-
-        println!("Part one: {:?}", monkeys[0].inspections * monkeys[1].inspections);
-
-        let mut monkeys: Vec<Monkey> = source_monkeys.clone();
+        Ok(Self { monkeys })
+    }
+}
 
-        let common_multiple = monkeys.iter().map(|m| m.test_divisible_by).fold(1, |mut cm, x| {
-            cm *= x;
-            cm
-        });
+impl crate::aoc::Day for Day {
+    type Output = u128;
 
-        for _ in 0..10000 {
-            for i in 0..monkeys.len() {
-                let monkey = monkeys[i].clone();
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 10605)]
+    }
 
-                for starting_item in &monkey.starting_items {
-                    let worry_level = monkey.operation.execute(*starting_item) % common_multiple;
-                    let new_monkey_idx = if worry_level % monkey.test_divisible_by == 0 {
-                        monkey.test_divisible_result[0]
-                    } else {
-                        monkey.test_divisible_result[1]
-                    };
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 2713310158)]
+    }
 
-                    monkeys[i].increase_inspection(1);
-                    monkeys[new_monkey_idx].starting_items.push(worry_level);
-                }
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(monkey_business(self.monkeys.clone(), 20, |worry_level| worry_level / 3))
+    }
 
-                monkeys[i].starting_items.clear();
-            }
-        }
-        monkeys.sort_by(|a, b| {
-            b.inspections.cmp(&a.inspections)
-        });
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let common_multiple = self.monkeys.iter().map(|m| m.test_divisible_by).product::<u128>();
 
-        println!("Part two: {}", monkeys[0].inspections * monkeys[1].inspections);
+        Ok(monkey_business(self.monkeys.clone(), 10000, |worry_level| worry_level % common_multiple))
     }
-}
\ No newline at end of file
+}
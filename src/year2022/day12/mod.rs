@@ -1,7 +1,9 @@
-use std::collections::{HashMap, VecDeque};
-use std::fs;
 use std::str::FromStr;
 
+use crate::aoc::Error;
+use crate::utils::search;
+
+#[derive(Default, Clone, Debug)]
 struct Grid {
     starting_position: Pos,
     ending_position: Pos,
@@ -35,29 +37,15 @@ impl Grid {
     fn idx2d(&self, pos: &Pos) -> u8 { self.map[pos.y * self.width + pos.x] }
 
     fn bfs(&self, start: Pos) -> Option<usize> {
-        let mut visited = HashMap::new();
-        visited.insert(start, 0);
-
-
-        let mut q = VecDeque::new();
-        q.push_back(start);
-
-        while let Some(current) = q.pop_front() {
-            for direction in self.neighbours(current) {
-                let position = current.move_towards_direction(direction);
-
-                #[allow(clippy::map_entry)]
-                if !visited.contains_key(&position) {
-                    visited.insert(position, visited[&current] + 1);
-                    q.push_back(position);
-                }
-            }
-        }
+        let search = search::bfs(start, |&current| {
+            self.neighbours(current).into_iter().map(move |direction| current.move_towards_direction(direction))
+        });
 
-        visited.get(&self.ending_position).copied()
+        search.distances.get(&self.ending_position).copied()
     }
 }
 
+#[derive(Debug, Clone, Copy)]
 enum Direction {
     Left,
     Right,
@@ -88,7 +76,7 @@ impl Pos {
 
 
 impl FromStr for Grid {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut width = None;
@@ -97,11 +85,11 @@ impl FromStr for Grid {
         let mut end = Pos { x: 0, y: 0};
 
 
-        s.lines().enumerate().for_each(|(y, line)| {
+        s.lines().filter(|line| !line.trim().is_empty()).enumerate().for_each(|(y, line)| {
             if width.is_none() {
-                width = Some(line.chars().count());
+                width = Some(line.trim().chars().count());
             }
-            line.chars().enumerate().for_each(|(x, char)| {
+            line.trim().chars().enumerate().for_each(|(x, char)| {
                 let height = match char {
                     'S' => {
                         start.set(x, y);
@@ -124,42 +112,56 @@ impl FromStr for Grid {
         Ok(Grid {
             starting_position: start,
             ending_position: end,
-            width: width.unwrap(),
+            width: width.ok_or(Error::Parse(s.to_string()))?,
             map,
         })
     }
 }
 
 
-pub struct Day12;
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    grid: Grid,
+}
 
-impl crate::year2022::Day for Day12 {
-    fn date(&self) -> (i32, i32) { (12, 2022) }
+impl FromStr for Day {
+    type Err = Error;
 
-    fn run(&self) {
-        // let mut grid: Grid = fs::read_to_string("src/year_2022/day12/test.txt")
-        let grid: Grid = fs::read_to_string("src/year_2022/day12/input.txt")
-            .unwrap()
-            .parse()
-            .unwrap();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { grid: s.parse()? })
+    }
+}
+
+impl crate::aoc::Day for Day {
+    type Output = usize;
+
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 31)]
+    }
 
-        let shortest_path = grid.bfs(grid.starting_position).unwrap();
-        println!("Part one: {shortest_path}");
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 29)]
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.grid.bfs(self.grid.starting_position).ok_or(Error::NoSolutionFound)?)
+    }
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let grid = &self.grid;
+        let mut minimum = None;
 
-        let mut minimum = usize::MAX;
         for y in 0..grid.map.len() / grid.width {
             for x in 0..grid.width {
                 let pos = Pos { x, y };
                 if grid.idx2d(&pos) == 0 {
-
                     if let Some(length) = grid.bfs(pos) {
-                        if length < minimum {
-                            minimum = length;
-                        }
+                        minimum = Some(minimum.map_or(length, |minimum: usize| minimum.min(length)));
                     }
                 }
             }
         }
-        println!("Part two: {minimum}");
+
+        Ok(minimum.ok_or(Error::NoSolutionFound)?)
     }
-}
\ No newline at end of file
+}
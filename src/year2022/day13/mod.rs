@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
-use std::fs;
+use std::str::FromStr;
 
-pub struct Day13;
+use crate::aoc::Error;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum ListElement {
@@ -11,27 +11,27 @@ enum ListElement {
 
 impl PartialOrd for ListElement {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ListElement {
+    fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Self::Integer(a), Self::Integer(b)) => a.partial_cmp(b),
-            (Self::List(a), Self::List(b)) => a.partial_cmp(b),
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+            (Self::List(a), Self::List(b)) => a.cmp(b),
             (num_a, Self::List(b)) => {
                 let a = List { elements: vec![num_a.clone()] };
-                a.partial_cmp(b)
+                a.cmp(b)
             }
             (Self::List(a), num_b) => {
                 let b = List { elements: vec![num_b.clone()] };
-                a.partial_cmp(&b)
+                a.cmp(&b)
             }
         }
     }
 }
 
-impl Ord for ListElement {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct List {
     elements: Vec<ListElement>
@@ -39,33 +39,30 @@ struct List {
 
 impl PartialOrd for List {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        for (a, b) in self.elements.iter().zip(other.elements.iter()) {
-            match a.cmp(b) {
-                Ordering::Less => return Some(Ordering::Less),
-                Ordering::Greater => return Some(Ordering::Greater),
-                _ => continue,
-            }
-        }
-        Some(self.elements.len().cmp(&other.elements.len()))
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for List {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        for (a, b) in self.elements.iter().zip(other.elements.iter()) {
+            match a.cmp(b) {
+                Ordering::Less => return Ordering::Less,
+                Ordering::Greater => return Ordering::Greater,
+                _ => continue,
+            }
+        }
+        self.elements.len().cmp(&other.elements.len())
     }
 }
 
-
-// impl Part
-
 impl List {
     pub fn empty() -> Self {
         Self { elements: vec![] }
     }
 
 
-    pub fn from(list_str: &str) -> Self {
+    pub fn from(list_str: &str) -> Result<Self, Error> {
         let mut list = List::empty();
 
         let mut splits = vec![];
@@ -74,11 +71,7 @@ impl List {
             match char {
                 '[' => indent_level += 1,
                 ']' => indent_level -= 1,
-                ',' => {
-                    if indent_level == 1 {
-                        splits.push(i);
-                    }
-                },
+                ',' if indent_level == 1 => splits.push(i),
                 _ => { }
             }
         }
@@ -94,42 +87,63 @@ impl List {
 
         for element in split {
             if element.starts_with('[') {
-                list.elements.push(ListElement::List(List::from(element)))
+                list.elements.push(ListElement::List(List::from(element)?))
             } else if !element.is_empty() {
-                list.elements.push(ListElement::Integer(element.parse::<u8>().unwrap()));
+                list.elements.push(ListElement::Integer(element.parse::<u8>()?));
             }
         }
 
-        list
+        Ok(list)
     }
 }
 
-impl crate::year2022::Day for Day13 {
-    fn date(&self) -> (i32, i32) { (13, 2022) }
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    pairs: Vec<(List, List)>,
+}
 
-    fn run(&self) {
-        let input = fs::read_to_string("src/year_2022/day13/input.txt").unwrap();
-        let pairs: Vec<&str> = input.split("\r\n\r\n").collect();
-        let mut lists = vec![];
-        let mut sum = 0;
-        for (index, line) in pairs.iter().enumerate() {
-            let l: Vec<&str> = line.lines().collect();
-            let list_one_str = l[0].trim();
-            let list_two_str = l[1].trim();
+impl FromStr for Day {
+    type Err = Error;
 
-            let list_one = List::from(list_one_str);
-            let list_two = List::from(list_two_str);
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pairs = s.replace("\r\n", "\n")
+            .split("\n\n")
+            .map(|pair| {
+                if let [list_one_str, list_two_str] = pair.lines().map(str::trim).collect::<Vec<&str>>()[..] {
+                    return Ok((List::from(list_one_str)?, List::from(list_two_str)?));
+                }
 
-            if list_one < list_two { // right order
-                sum += index + 1;
-            }
+                Err(Error::Parse(pair.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-            lists.push(list_one);
-            lists.push(list_two);
-        }
+        Ok(Self { pairs })
+    }
+}
 
-        println!("Part one:\t\t{}", sum);
+impl crate::aoc::Day for Day {
+    type Output = usize;
 
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 13)]
+    }
+
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 140)]
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(self.pairs.iter()
+            .enumerate()
+            .filter(|(_, (list_one, list_two))| list_one < list_two) // right order
+            .map(|(index, _)| index + 1)
+            .sum())
+    }
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let mut lists = self.pairs.iter()
+            .flat_map(|(list_one, list_two)| [list_one.clone(), list_two.clone()])
+            .collect::<Vec<_>>();
 
         let dp_1 = List { elements: vec![ListElement::List(List { elements: vec![ListElement::Integer(2)] })] };
         let dp_2 = List { elements: vec![ListElement::List(List { elements: vec![ListElement::Integer(6)] })] };
@@ -137,10 +151,9 @@ impl crate::year2022::Day for Day13 {
         lists.push(dp_2.clone());
         lists.sort();
 
-        let dp_1_index = lists.iter().position(|l| *l == dp_1).unwrap() + 1;
-        let dp_2_index = lists.iter().position(|l| *l == dp_2).unwrap() + 1;
+        let dp_1_index = lists.iter().position(|l| *l == dp_1).ok_or(Error::NoSolutionFound)? + 1;
+        let dp_2_index = lists.iter().position(|l| *l == dp_2).ok_or(Error::NoSolutionFound)? + 1;
 
-
-        println!("Part two:\t\t{}", dp_1_index * dp_2_index);
+        Ok(dp_1_index * dp_2_index)
     }
-}
\ No newline at end of file
+}
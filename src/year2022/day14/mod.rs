@@ -1,5 +1,7 @@
 use std::fmt::{Debug, Formatter};
-use std::fs;
+use std::str::FromStr;
+
+use crate::aoc::Error;
 
 #[derive(Clone, PartialEq)]
 enum Material {
@@ -117,7 +119,7 @@ impl Cave {
         });
 
         let mut voided = false;
-        let mut current_pos = sand.clone();
+        let mut current_pos = sand;
 
         let mut move_sim = 0;
 
@@ -159,7 +161,7 @@ impl Cave {
         true
     }
 
-    fn from_points(points: &Vec<Vec<Position>>) -> Self {
+    fn from_points(points: &[Vec<Position>]) -> Self {
         let mut width_min: usize = usize::MAX;
         let height_min: usize = 0;
 
@@ -239,63 +241,76 @@ fn order(a: usize, b: usize) -> (usize, usize) {
     if a <= b { (a, b) } else { (b, a) }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 struct Position {
     x: usize,
     y: usize
 }
 
 impl Position {
-    fn from_str(s: &str) -> Vec<Self> {
-        return s.split(" -> ").map(|coord| {
+    fn parse_path(s: &str) -> Result<Vec<Self>, Error> {
+        s.split(" -> ").map(|coord| {
             if let [x, y] = coord.split(',').collect::<Vec<&str>>()[..] {
-                return Position {x: x.parse::<usize>().unwrap(), y: y.parse::<usize>().unwrap() };
+                return Ok(Position { x: x.trim().parse::<usize>()?, y: y.trim().parse::<usize>()? });
             }
 
-            panic!("unexpected")
-        }).collect::<Vec<Position>>();
+            Err(Error::Parse(coord.to_string()))
+        }).collect::<Result<Vec<Position>, _>>()
     }
 }
 
-pub struct Day14;
-impl crate::year2022::Day for Day14 {
-    fn date(&self) -> (i32, i32) { (14, 2022) }
+fn count_units(rocks: &[Vec<Position>]) -> usize {
+    let mut cave: Cave = Cave::from_points(rocks);
 
-    fn run(&self) {
-        let mut rocks = Vec::new();
-        // let input= fs::read_to_string("src/year_2022/day14/test.txt")
-        let input = fs::read_to_string("src/year_2022/day14/input.txt")
-            .unwrap();
+    let mut units = 0;
+    while cave.unit_move() {
+        units += 1;
+    }
 
-        for line in input.lines() {
-            let rock_formation = Position::from_str(line);
-            rocks.push(rock_formation);
-        }
+    units
+}
 
-        let mut cave: Cave = Cave::from_points(&rocks);
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    rocks: Vec<Vec<Position>>,
+}
 
-        let mut units = 0;
-        while cave.unit_move() {
-            units += 1;
-        }
+impl FromStr for Day {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            rocks: s.lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(Position::parse_path)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
 
-        println!("Part 1: {}", units);
+impl crate::aoc::Day for Day {
+    type Output = usize;
 
-        let cave: Cave = Cave::from_points(&rocks);
-        let inf_floor = cave.height + 1;
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 24)]
+    }
 
-        let rock_formation = Position::from_str(&format!("0,{inf_floor} -> 1000,{inf_floor}"));
-        rocks.push(rock_formation);
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 93)]
+    }
 
-        let mut cave: Cave = Cave::from_points(&rocks);
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(count_units(&self.rocks))
+    }
 
-        let mut units = 0;
-        while cave.unit_move() {
-            units += 1;
-        }
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let cave: Cave = Cave::from_points(&self.rocks);
+        let inf_floor = cave.height + 1;
 
-        units += 1;
+        let mut rocks = self.rocks.clone();
+        rocks.push(vec![Position { x: 0, y: inf_floor }, Position { x: 1000, y: inf_floor }]);
 
-        println!("Part 2: {}", units);
+        // the unit resting on the source is not counted by the simulation
+        Ok(count_units(&rocks) + 1)
     }
-}
\ No newline at end of file
+}
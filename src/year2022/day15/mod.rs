@@ -1,7 +1,8 @@
 use std::collections::HashSet;
-use std::fs;
 use std::str::FromStr;
 
+use crate::aoc::Error;
+
 #[derive(Debug, Eq, Hash, PartialEq, Clone)]
 struct Position {
     x: i32,
@@ -14,7 +15,7 @@ impl Position {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct SensorBeaconPair {
     sensor: Position,
     beacon: Position
@@ -28,15 +29,15 @@ impl SensorBeaconPair {
 }
 
 impl FromStr for SensorBeaconPair {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
         if let ["Sensor", "at", s_x_eq, s_y_eq, "closest", "beacon", "is", "at", b_x_eq, b_y_eq] = line.split(' ').collect::<Vec<&str>>()[..] {
-            let x_sensor: i32 = s_x_eq.split('=').collect::<Vec<&str>>()[1].replace(',', "").trim().parse::<i32>().unwrap();
-            let y_sensor: i32 = s_y_eq.split('=').collect::<Vec<&str>>()[1].replace(':', "").trim().parse::<i32>().unwrap();
+            let x_sensor: i32 = coordinate(s_x_eq, ',')?;
+            let y_sensor: i32 = coordinate(s_y_eq, ':')?;
 
-            let x_beacon: i32 = b_x_eq.split('=').collect::<Vec<&str>>()[1].replace(',', "").trim().parse::<i32>().unwrap();
-            let y_beacon: i32 = b_y_eq.split('=').collect::<Vec<&str>>()[1].replace(':', "").trim().parse::<i32>().unwrap();
+            let x_beacon: i32 = coordinate(b_x_eq, ',')?;
+            let y_beacon: i32 = coordinate(b_y_eq, ':')?;
 
             return Ok(SensorBeaconPair{
                 sensor: Position { x: x_sensor, y: y_sensor },
@@ -44,11 +45,16 @@ impl FromStr for SensorBeaconPair {
             });
         }
 
-        Err(String::from("Failed"))
+        Err(Error::Parse(line.to_string()))
     }
 }
 
-fn count_free_in_row(sensors: &Vec<SensorBeaconPair>, row: i32) -> usize {
+fn coordinate(assignment: &str, separator: char) -> Result<i32, Error> {
+    let (_, value) = assignment.split_once('=').ok_or(Error::Parse(assignment.to_string()))?;
+    Ok(value.replace(separator, "").trim().parse::<i32>()?)
+}
+
+fn count_free_in_row(sensors: &[SensorBeaconPair], row: i32) -> usize {
     let block_row_positions: HashSet<i32> =
     sensors.iter()
         .map(|p| &p.sensor)
@@ -105,39 +111,65 @@ fn pattern_from_center(sensor: &Position, radius: i32, area: i32) -> Vec<Positio
     result
 }
 
-fn find_signal(data: &Vec<SensorBeaconPair>, area: i32) -> Position {
+fn find_signal(data: &[SensorBeaconPair], area: i32) -> Option<Position> {
     for pair in data {
         for p in pattern_from_center(&pair.sensor, (pair.distance() + 1) as i32, area) {
             if !data.iter().any(|n| n.sensor.distance(&p) <= n.sensor.distance(&n.beacon)) {
-                return p;
+                return Some(p);
             }
         }
     }
 
-    panic!();
+    None
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct Day {
+    pairs: Vec<SensorBeaconPair>,
+    target_row: i32,
+    area: i32,
 }
 
-pub struct Day15;
-impl crate::year2022::Day for Day15 {
-    fn date(&self) -> (i32, i32) {
-        (15, 2022)
+impl FromStr for Day {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            pairs: s.lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(SensorBeaconPair::from_str)
+                .collect::<Result<Vec<SensorBeaconPair>, _>>()?,
+            target_row: 10,
+            area: 20,
+        })
     }
+}
 
-    fn run(&self) {
-        // let input = fs::read_to_string("src/year_2022/day15/test.txt")
-        let input = fs::read_to_string("src/year_2022/day15/input.txt")
-            .unwrap()
-            .lines()
-            .map(SensorBeaconPair::from_str)
-            .map(|r| r.unwrap())
-            .collect::<Vec<SensorBeaconPair>>();
+impl crate::aoc::Day for Day {
+    type Output = u64;
 
-        let target_row = 2000000;
+    fn test_cases_1() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 26)]
+    }
 
-        let result = count_free_in_row(&input, target_row);
-        println!("Part one: {}", result);
+    fn test_cases_2() -> Vec<(&'static str, Self::Output)> {
+        vec![(include_str!("test.txt"), 56000011)]
+    }
 
-        let pos = find_signal(&input, 4000000);
-        println!("Part two: {}", pos.x as u64 * 4000000_u64 + pos.y as u64);
+    fn after_test_1(&mut self) {
+        self.target_row = 2000000;
     }
-}
\ No newline at end of file
+
+    fn after_test_2(&mut self) {
+        self.area = 4000000;
+    }
+
+    fn solution1(&mut self) -> anyhow::Result<Self::Output> {
+        Ok(count_free_in_row(&self.pairs, self.target_row) as u64)
+    }
+
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let pos = find_signal(&self.pairs, self.area).ok_or(Error::NoSolutionFound)?;
+        Ok(pos.x as u64 * 4000000_u64 + pos.y as u64)
+    }
+}
@@ -1,7 +1,8 @@
 use std::ops::Range;
 use std::str::FromStr;
 
-use crate::aoc::Error;
+use crate::aoc::{Context, Error};
+use crate::utils::parse;
 
 #[derive(Default, Clone, Debug)]
 pub struct Relation {
@@ -121,7 +122,7 @@ impl crate::aoc::Day for Day {
             seeds = new;
         }
 
-        seeds.sort_by(|a, b| a.start.cmp(&b.start));
+        seeds.sort_by_key(|a| a.start);
         Ok(seeds[0].start)
     }
 }
@@ -139,40 +140,23 @@ impl FromStr for Day {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = s.lines();
-
-        let seeds: Vec<u64> = if let Some(line) = lines.next() {
-            if let ["seeds:", numbers @ ..] = &line.split(' ').collect::<Vec<_>>()[..] {
-                numbers.iter()
-                    .map(|number_str| number_str.parse::<u64>().unwrap_or(0))
-                    .collect::<Vec<_>>()
-            } else {
-                return Err(Error::StringParse(line.to_string()));
-            }
-        } else {
-            return Err(Error::StringParse(String::new()));
+        let sections = parse::sections(s);
+        let Some((seeds, maps)) = sections.split_first() else {
+            return Err(Error::Parse(String::from("empty input")));
         };
 
+        let seeds_field = parse::field_str(seeds, "seeds")?;
+        let seeds = parse::unsigned::<u64>(seeds_field).within(s, seeds_field)?;
+
         let mut relations = vec![];
-        let mut current_relation: FromToRelations = FromToRelations { relations: vec![] };
 
-        for line in lines {
-            if line.is_empty() {
-                if !current_relation.relations.is_empty() { // new relation
-                    relations.push(current_relation);
-                    current_relation = FromToRelations { relations: vec![] };
-                }
-                continue;
-            }
+        for map in maps {
+            let mut current_relation: FromToRelations = FromToRelations { relations: vec![] };
 
-            if line.contains(':') {
-                continue;
-            }
-
-            if let [destination_range_start, source_range_start, range_length] = line.split(' ').collect::<Vec<_>>()[..] {
-                let destination_range_start = destination_range_start.parse::<u64>()?;
-                let source_range_start = source_range_start.parse::<u64>()?;
-                let range_length = range_length.parse::<u64>()?;
+            for line in map.lines().filter(|line| !line.contains(':')) {
+                let [destination_range_start, source_range_start, range_length] = parse::unsigned::<u64>(line).within(s, line)?[..] else {
+                    return Err(Error::at("expected destination, source and length", s, line));
+                };
 
                 let relation = Relation {
                     destination_range: destination_range_start..(destination_range_start + range_length) - 1,
@@ -181,10 +165,10 @@ impl FromStr for Day {
 
                 current_relation.relations.push(relation);
             }
-        }
 
-        if !current_relation.relations.is_empty() { // new relation
-            relations.push(current_relation);
+            if !current_relation.relations.is_empty() {
+                relations.push(current_relation);
+            }
         }
 
         Ok(Self {
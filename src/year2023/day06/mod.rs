@@ -82,6 +82,6 @@ impl FromStr for Day {
             });
         }
 
-        Err(Error::StringParse(s.to_string()))
+        Err(Error::Parse(s.to_string()))
     }
 }
\ No newline at end of file
@@ -188,7 +188,7 @@ impl From<&Vec<Type>> for HandStrength {
         let is_two_pair = value.are_equal_distinct(2, &[]);
 
         if is_two_pair.0 {
-            let is_two_pair_second = value.are_equal_distinct(2, &[is_two_pair.1.clone()]);
+            let is_two_pair_second = value.are_equal_distinct(2, std::slice::from_ref(&is_two_pair.1));
 
             return if is_two_pair_second.0 {
                 HandStrength::TwoPair
@@ -231,6 +231,7 @@ impl PartialEq<Self> for Hand {
     }
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd<Self> for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         let cmp = self.hand_strength.cmp(&other.hand_strength);
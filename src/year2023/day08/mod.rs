@@ -1,10 +1,8 @@
-use std::cell::RefCell;
 use std::collections::HashMap;
-use std::rc::{Rc, Weak};
 use std::str::FromStr;
 
 use crate::aoc::Error;
-use crate::utils::tree::Node;
+use crate::utils::tree::Graph;
 
 #[derive(Debug, Clone, Default)]
 enum Instruction {
@@ -15,8 +13,7 @@ enum Instruction {
 
 #[derive(Debug, Clone, Default)]
 pub struct Day {
-    tree: Rc<RefCell<Node<String>>>,
-    raw_data: String,
+    network: Graph<String>,
     lr_instructions: Vec<Instruction>,
 }
 
@@ -36,19 +33,18 @@ impl crate::aoc::Day for Day {
     }
 
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
-        let mut current = Rc::clone(&self.tree);
-        let destination = current.borrow().search_in_tree_predicate(|a| a == "ZZZ").ok_or(Error::StringParse(String::from("Cant find destination in tree")))?;
+        let mut current = self.network.find(|a| a == "AAA").ok_or(Error::Parse(String::from("Cant find start in network")))?;
+        let destination = self.network.find(|a| a == "ZZZ").ok_or(Error::Parse(String::from("Cant find destination in network")))?;
         let mut index = 0;
         let mut counter = 0;
 
-        while current.as_ptr() != destination.as_ptr() {
+        while current != destination {
             let s = match &self.lr_instructions[index] {
                 Instruction::Left => 0,
                 Instruction::Right => 1
             };
 
-            let child = Rc::clone(&current.borrow().children[s]);
-            current = child;
+            current = self.network.children(current)[s];
             index = (index + 1) % self.lr_instructions.len();
 
             counter += 1;
@@ -58,9 +54,8 @@ impl crate::aoc::Day for Day {
     }
 
     fn solution2(&mut self) -> anyhow::Result<Self::Output> {
-        let k = Day::from_str_modified(&self.raw_data);
-        let mut all_currents = k.0;
-        let instructions = k.1;
+        let mut all_currents = self.network.find_all(|a| a.ends_with('A'));
+        let instructions = &self.lr_instructions;
 
         let mut index = 0;
 
@@ -77,12 +72,11 @@ impl crate::aoc::Day for Day {
                     continue;
                 }
 
-                let child = Rc::clone(&current.borrow().children[instruction]);
-                *current = child;
+                *current = self.network.children(*current)[instruction];
 
                 counters[current_index].counter += 1;
 
-                if current.borrow().value.ends_with('Z') {
+                if self.network.value(*current).ends_with('Z') {
                     counters[current_index].is_finished = true;
                 }
             }
@@ -158,95 +152,19 @@ impl FromStr for Day {
             hash_map.insert(node_name, childs);
         }
 
-        let root = create_node(&hash_map, "AAA", None, &mut HashMap::new());
+        let mut network = Graph::new();
+        let ids = hash_map.keys().map(|name| (name.clone(), network.add_node(name.clone()))).collect::<HashMap<_, _>>();
 
-        Ok(Self {
-            tree: root,
-            raw_data: s.to_string(),
-            lr_instructions: instructions,
-        })
-    }
-}
-
-impl Day {
-    #[allow(clippy::type_complexity)]
-    fn from_str_modified(s: &str) -> (Vec<Rc<RefCell<Node<String>>>>, Vec<Instruction>) {
-        let mut iter = s.lines();
-        let lri = iter.next().unwrap_or("");
-        let mut instructions = vec![];
-
-        for char in lri.chars() {
-            instructions.push(match char {
-                'L' => Instruction::Left,
-                'R' => Instruction::Right,
-                _ => Instruction::Left
-            });
-        }
-
-
-        let mut hash_map = HashMap::new();
-        for line in s.lines() {
-            if line.is_empty() || !line.contains('=') {
-                continue;
-            }
-
-            let node_names = line
-                .split('=')
-                .map(|a| a.trim())
-                .collect::<Vec<_>>();
-
-            let node_name = node_names[0].to_string();
-            let temp = node_names[1].split(", ").map(|a| a.replace(['(', ')'], "")).collect::<Vec<_>>();
-
-            let childs: [String; 2] = [temp[0].to_string(), temp[1].to_string()];
-
-            hash_map.insert(node_name, childs);
-        }
-
-        let mut c = vec![];
-
-        for key in hash_map.keys() {
-            if key.ends_with('A') {
-                let root = create_node(&hash_map, key, None, &mut HashMap::new());
-                c.push(root);
+        for (node_name, childs) in &hash_map {
+            for child in childs {
+                let child = *ids.get(child).ok_or(Error::Parse(format!("Unknown node {child}")))?;
+                network.add_edge(ids[node_name], child);
             }
         }
 
-        (c, instructions)
+        Ok(Self {
+            network,
+            lr_instructions: instructions,
+        })
     }
 }
-
-fn create_node(map: &HashMap<String, [String; 2]>, value: &str, parent: Option<Weak<RefCell<Node<String>>>>, currently_build: &mut HashMap<String, Rc<RefCell<Node<String>>>>) -> Rc<RefCell<Node<String>>> {
-    return if let Some(pointers) = map.get(value) {
-        let children = vec![];
-
-        let childs_parent = Rc::new(RefCell::new(Node {
-            value: value.to_string(),
-            parent,
-            children,
-        }));
-
-        if !currently_build.contains_key(value) {
-            currently_build.insert(value.to_string(), Rc::clone(&childs_parent));
-        }
-
-
-        for child_value in pointers {
-            if let Some(already_build_child) = currently_build.get(child_value) {
-                childs_parent.borrow_mut().children.push(Rc::clone(already_build_child));
-            } else {
-                let child = create_node(map, child_value, Some(Weak::clone(&Rc::downgrade(&childs_parent))), currently_build);
-                currently_build.insert(child_value.to_string(), Rc::clone(&child));
-                childs_parent.borrow_mut().children.push(child);
-            }
-        }
-
-        childs_parent
-    } else { // end of tree
-        Rc::new(RefCell::new(Node {
-            value: value.to_string(),
-            parent,
-            children: vec![],
-        }))
-    };
-}
\ No newline at end of file
@@ -108,7 +108,7 @@ impl Part1And2 for Grid<char> {
         let mut positions = vec![];
 
         loop {
-            let next = self.step(current, previous);
+            let next = Part1And2::step(self, current, previous);
             previous = current;
             current = next;
             positions.push(current);
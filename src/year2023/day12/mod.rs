@@ -53,15 +53,13 @@ fn count_combinations<'a>(config: &'a str, numbers: &'a [usize], cache: &mut Has
 }
 
 fn repeat_and_separate(s: &str, count: usize, separator: &str) -> String {
-    std::iter::repeat(s)
-        .take(count)
+    std::iter::repeat_n(s, count)
         .collect::<Vec<&str>>()
         .join(separator)
 }
 
 fn repeat_vec(v: &[usize], count: usize) -> Vec<usize> {
-    std::iter::repeat(v)
-        .take(count)
+    std::iter::repeat_n(v, count)
         .flatten()
         .cloned()
         .collect()
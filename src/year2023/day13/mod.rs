@@ -4,6 +4,7 @@ use itertools::Itertools;
 
 use crate::aoc::Error;
 use crate::utils::grid::Grid;
+use crate::utils::parse;
 
 #[derive(Debug, Clone, Default)]
 pub struct Day {
@@ -144,8 +145,7 @@ impl FromStr for Day {
     type Err = Error;
 
     fn from_str(src: &str) -> Result<Self, Self::Err> {
-        let grids_str: Vec<&str> = src.split("\r\n\r\n")
-            .collect();
+        let grids_str = parse::sections(src);
 
         let mut grids = vec![];
 
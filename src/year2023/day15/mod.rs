@@ -22,7 +22,7 @@ impl FromStr for Operation {
                 Ok(Operation::Minus)
             },
             _ => {
-                Err(Error::StringParse("Could not parse".to_string()))
+                Err(Error::Parse("Could not parse".to_string()))
             }
         }
     }
@@ -92,7 +92,7 @@ fn energize(grid: &Grid<char>, start_position: Vec2, direction: Vec2) -> usize {
             continue;
         }
 
-        if visited.get(current_beam.deref()).is_some() {
+        if visited.contains(current_beam.deref()) {
             current_beams.remove(0);
             continue;
         }
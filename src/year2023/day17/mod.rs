@@ -1,10 +1,9 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashSet};
 use std::fmt::Debug;
 use std::str::FromStr;
 
 use crate::aoc::Error;
 use crate::utils::grid::{Grid};
+use crate::utils::search;
 
 #[derive(Debug, Clone, Default)]
 pub struct Day {
@@ -68,8 +67,8 @@ impl crate::aoc::Day for Day {
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
 struct Movement {
-    heat_loss: usize,
     y: isize,
     x: isize,
     direction_y: isize,
@@ -78,98 +77,36 @@ struct Movement {
 }
 
 fn dijkstra_algorithm(grid: &Grid<usize>, min_steps: usize, push_allowed_without_constraint: bool, end_predicate: fn(&Movement, &Grid<usize>) -> bool) -> Option<usize> {
-    let mut seen = HashSet::new();
-    let mut priority_queue = BinaryHeap::new();
-
-    priority_queue.push(Reverse(Movement {
-        heat_loss: 0,
-        y: 0,
-        x: 0,
-        direction_y: 0,
-        direction_x: 0,
-        amount_steps: 0,
-    }));
-
-    while let Some(Reverse(Movement { heat_loss, y, x, direction_y, direction_x, amount_steps})) = priority_queue.pop() {
-        // dont include heat loss. if so and we end up in a loop, the heat_loss will grow, but the other parts are still the same
-        if seen.contains(&(y, x, direction_y, direction_x, amount_steps)) {
-            continue;
-        }
-
-        if end_predicate(&Movement {
-            heat_loss,
-            y,
-            x,
-            direction_y,
-            direction_x,
-            amount_steps,
-        }, grid) {
-            return Some(heat_loss);
-        }
+    let start = Movement { y: 0, x: 0, direction_y: 0, direction_x: 0, amount_steps: 0 };
 
-        seen.insert((y, x, direction_y, direction_x, amount_steps));
-
-        if amount_steps < min_steps && (direction_y, direction_x) != (0, 0) {
-            let next_y = y + direction_y;
-            let next_x = x + direction_x;
+    let successors = |&Movement { y, x, direction_y, direction_x, amount_steps }: &Movement| {
+        let mut next = Vec::new();
+        let mut push = |direction_y: isize, direction_x: isize, amount_steps: usize| {
+            let (next_y, next_x) = (y + direction_y, x + direction_x);
 
             if grid.in_bounds(next_x, next_y) {
-                priority_queue.push(Reverse(Movement {
-                    heat_loss: heat_loss + grid[(next_x as usize, next_y as usize)],
-                    y: next_y,
-                    x: next_x,
-                    direction_y,
-                    direction_x,
-                    amount_steps: amount_steps + 1,
-                }))
+                next.push((Movement { y: next_y, x: next_x, direction_y, direction_x, amount_steps }, grid[(next_x as usize, next_y as usize)]));
             }
+        };
+
+        if amount_steps < min_steps && (direction_y, direction_x) != (0, 0) {
+            push(direction_y, direction_x, amount_steps + 1);
         }
 
         if push_allowed_without_constraint || amount_steps >= 4 || (direction_x, direction_y) == (0, 0) {
             for (next_direction_y, next_direction_x) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
-                if (next_direction_y, next_direction_x) != (direction_y, direction_x) && (next_direction_y, next_direction_x) != (-direction_y, -direction_x){
-                    let next_y = y + next_direction_y;
-                    let next_x = x + next_direction_x;
-
-                    if grid.in_bounds(next_x, next_y) {
-                        priority_queue.push(Reverse(Movement {
-                            heat_loss: heat_loss + grid[(next_x as usize, next_y as usize)],
-                            y: next_y,
-                            x: next_x,
-                            direction_y: next_direction_y,
-                            direction_x: next_direction_x,
-                            amount_steps: 1
-                        }))
-                    }
+                if (next_direction_y, next_direction_x) != (direction_y, direction_x) && (next_direction_y, next_direction_x) != (-direction_y, -direction_x) {
+                    push(next_direction_y, next_direction_x, 1);
                 }
             }
         }
-    }
 
-    None
-}
+        next
+    };
 
-
-impl PartialOrd for Movement {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.heat_loss.partial_cmp(&other.heat_loss)
-    }
-}
-
-impl Ord for Movement {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
-    }
+    search::dijkstra(start, successors, |movement| end_predicate(movement, grid)).map(|(_, heat_loss)| heat_loss)
 }
 
-impl PartialEq for Movement {
-    fn eq(&self, other: &Self) -> bool {
-        self.heat_loss == other.heat_loss
-    }
-}
-
-impl Eq for Movement {}
-
 
 impl FromStr for Day {
     type Err = Error;
@@ -2,7 +2,8 @@ use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::str::FromStr;
 
-use crate::aoc::Error;
+use crate::aoc::{Context, Error};
+use crate::utils::parse;
 
 #[derive(Debug, Clone, Copy)]
 enum Part {
@@ -207,12 +208,15 @@ impl FromStr for Workflow {
         if let [name, conditions @ ..] = &s.split(&['{', ',', '}']).collect::<Vec<_>>()[..] {
             return Ok(Self {
                 name: name.to_string(),
-                conditions: conditions.iter().filter(|c| !c.trim().is_empty()).filter_map(|c| c.parse().ok()).collect(),
+                conditions: conditions.iter()
+                    .filter(|c| !c.trim().is_empty())
+                    .map(|c| c.parse().within(s, c))
+                    .collect::<Result<_, _>>()?,
             });
         }
 
 
-        Err(Error::StringParse(s.to_string()))
+        Err(Error::at("expected a workflow like px{a<2006:qkq,rfg}", s, s))
     }
 }
 
@@ -225,7 +229,7 @@ impl FromStr for PartDescription {
             "m" => Self::Musical,
             "a" => Self::Aerodynamic,
             "s" => Self::Shiny,
-            _ => panic!()
+            _ => return Err(Error::at("expected one of x, m, a or s", s, s))
         })
     }
 }
@@ -237,7 +241,7 @@ impl FromStr for Operator {
         Ok(match s {
             ">" => Self::GreaterThan,
             "<" => Self::LessThan,
-            _ => panic!()
+            _ => return Err(Error::at("expected < or >", s, s))
         })
     }
 }
@@ -246,20 +250,19 @@ impl FromStr for Condition {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.replace('<', " < ").replace('>', " > ").replace(':', " : ");
-        let s_ref = s.split(' ').collect::<Vec<_>>();
-        if let [part, operator, threshold, ":", next_rule] = &s_ref[..] {
-            return Ok(Condition::Conditional {
-                part: part.parse().unwrap_or(PartDescription::Aerodynamic),
-                operator: operator.parse().unwrap_or(Operator::LessThan),
-                threshold: threshold.parse::<usize>().unwrap_or(0),
+        let Some((check, next_rule)) = s.split_once(':') else {
+            return Ok(Condition::ConditionLess(s.to_string()));
+        };
+
+        match (check.get(..1), check.get(1..2), check.get(2..)) {
+            (Some(part), Some(operator), Some(threshold)) if !threshold.is_empty() => Ok(Condition::Conditional {
+                part: part.parse().within(s, part)?,
+                operator: operator.parse().within(s, operator)?,
+                threshold: threshold.parse::<usize>().within(s, threshold)?,
                 next_rule: next_rule.to_string(),
-            });
-        } else if let [next_rule] = &s_ref[..] {
-            return Ok(Condition::ConditionLess(next_rule.to_string()));
+            }),
+            _ => Err(Error::at("expected a condition like a<2006", s, check)),
         }
-
-        Err(Error::StringParse(s))
     }
 }
 
@@ -268,35 +271,37 @@ impl FromStr for Day {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let all_lines = s.split("\r\n\r\n").collect::<Vec<_>>();
+        let [workflows, metal_shapes] = parse::sections(s)[..] else {
+            return Err(Error::at("expected workflows and parts separated by an empty line", s, s));
+        };
 
         Ok(Self {
-            workflows: all_lines[0].lines()
-                .filter_map(|line| line.parse().ok())
-                .map(|a: Workflow| (a.name.to_string(), a.clone()))
-                .collect::<HashMap<String, Workflow>>(),
-            metal_shapes: all_lines[1].lines().map(|line| {
-                let k = line.replace(['{', '}'], "");
-                let parts = k.split(',').collect::<Vec<_>>();
+            workflows: workflows.lines()
+                .map(|line| line.parse().within(s, line))
+                .map(|a: Result<Workflow, _>| a.map(|a| (a.name.to_string(), a)))
+                .collect::<Result<HashMap<String, Workflow>, _>>()?,
+            metal_shapes: metal_shapes.lines().map(|line| {
+                let parts = line.trim_start_matches('{').trim_end_matches('}').split(',').collect::<Vec<_>>();
 
                 let mut r: [Part; 4] = [Part::Musical(0); 4];
 
-                for (i, part_str) in parts.iter().enumerate() {
+                for (i, part_str) in parts.iter().enumerate().take(4) {
                     if let [part, number] = part_str.split('=').collect::<Vec<_>>()[..] {
+                        let value = number.parse().within(s, number)?;
                         let part = match part {
-                            "x" => Part::ExtremelyCoolLooking(number.parse().unwrap_or(0)),
-                            "m" => Part::Musical(number.parse().unwrap_or(0)),
-                            "a" => Part::Aerodynamic(number.parse().unwrap_or(0)),
-                            "s" => Part::Shiny(number.parse().unwrap_or(0)),
-                            _ => panic!()
+                            "x" => Part::ExtremelyCoolLooking(value),
+                            "m" => Part::Musical(value),
+                            "a" => Part::Aerodynamic(value),
+                            "s" => Part::Shiny(value),
+                            _ => return Err(Error::at("expected one of x, m, a or s", s, part))
                         };
 
                         r[i] = part;
                     }
                 }
 
-                r
-            }).collect(),
+                Ok(r)
+            }).collect::<Result<_, Error>>()?,
         })
     }
 }
\ No newline at end of file
@@ -17,8 +17,8 @@ impl FromStr for Day {
 
         for line in s.lines() {
             let mut parts = line.split_whitespace();
-            left_list.push(parts.next().ok_or(Error::StringParse("Missing left value".to_string()))?.parse()?);
-            right_list.push(parts.next().ok_or(Error::StringParse("Missing right value".to_string()))?.parse()?);
+            left_list.push(parts.next().ok_or(Error::Parse("Missing left value".to_string()))?.parse()?);
+            right_list.push(parts.next().ok_or(Error::Parse("Missing right value".to_string()))?.parse()?);
         }
 
         Ok(Self { left_list, right_list })
@@ -11,17 +11,7 @@ impl FromStr for Day {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut width = 0;
-        let grid = s.lines()
-            .map(|line| {
-                width = line.len();
-                line.chars().collect::<Vec<char>>()
-            })
-            .flatten()
-            .collect::<Vec<char>>();
-
-        let height = s.lines().count();
-        Ok(Self {grid: Grid::new(width, height, grid) })
+        Ok(Self { grid: Grid::from_raw_input(s)? })
     }
 }
 
@@ -69,7 +59,7 @@ MXMXAXMASX", 9)]
                 let word: String = (0..target_word.len())
                     .map(|i| self.grid.get(((x as i32 + i as i32 * dx) as usize, (y as i32 + i as i32 * dy) as usize)))
                     .take_while(|&c| c.is_some())
-                    .filter_map(|c| c)
+                    .flatten()
                     .collect();
                 word == target_word || word == reversed_target_word
             })
@@ -21,7 +21,7 @@ impl FromStr for Day {
         let mut rules_done = false;
 
         for line in s.lines() {
-            if line == "" {
+            if line.is_empty() {
                 rules_done = true;
                 continue;
             }
@@ -157,7 +157,7 @@ fn check_update(update: &[i32], rules: &[Rule]) -> bool {
 }
 
 fn re_order(update: &[i32], rules: &[Rule]) -> Vec<i32> {
-    let mut order = update.iter().map(|a| *a).collect::<Vec<i32>>();
+    let mut order = update.to_vec();
 
     for i in 0..update.len() {
         for j in i + 1..update.len() {
@@ -34,11 +34,10 @@ impl FromStr for Day {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut width = 0;
         let grid = s.lines()
-            .map(|line| {
+            .flat_map(|line| {
                 width = line.len();
                 line.chars().map(|c| c.into()).collect::<Vec<Tile>>()
             })
-            .flatten()
             .collect::<Vec<Tile>>();
 
         let height = s.lines().count();
@@ -95,7 +94,7 @@ impl crate::aoc::Day for Day {
 
                     self.grid[(x, y)] = Tile::Obstacle;
 
-                    if let None = self.calculate_solution_1() {
+                    if self.calculate_solution_1().is_none() {
                         valid_positions += 1;
                     }
 
@@ -13,7 +13,7 @@ impl FromStr for Equation {
         let mut parts_str = s.split(" ");
         let result = parts_str
             .next()
-            .ok_or(Self::Err::StringParse("Cannot parse".to_string()))?
+            .ok_or(Self::Err::Parse("Cannot parse".to_string()))?
             .replace(":", "")
             .parse::<u64>()?;
 
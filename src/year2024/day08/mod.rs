@@ -14,11 +14,10 @@ impl FromStr for Day {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut width = 0;
         let grid = s.lines()
-            .map(|line| {
+            .flat_map(|line| {
                 width = line.len();
                 line.chars().map(|a| vec![a]).collect::<Vec<Vec<char>>>()
             })
-            .flatten()
             .collect::<Vec<Vec<char>>>();
 
         let height = s.lines().count();
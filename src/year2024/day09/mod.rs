@@ -20,8 +20,8 @@ impl FromStr for Day {
 
         for (index, char) in s.chars().enumerate() {
             vec.push(match index {
-                current_index  if current_index % 2 == 0 => Disk::Used(index / 2, char.to_digit(10).ok_or(crate::aoc::Error::StringParse(char.to_string()))? as usize),
-                _ => Disk::Free(char.to_digit(10).ok_or(crate::aoc::Error::StringParse(char.to_string()))? as usize),
+                current_index  if current_index % 2 == 0 => Disk::Used(index / 2, char.to_digit(10).ok_or(crate::aoc::Error::Parse(char.to_string()))? as usize),
+                _ => Disk::Free(char.to_digit(10).ok_or(crate::aoc::Error::Parse(char.to_string()))? as usize),
             })
         }
 
@@ -74,8 +74,8 @@ impl crate::aoc::Day for Day {
             let left_value = {
                 let right_block = &mut target[index_right];
                 let value = right_block.used.pop_back();
-                let cloned = value.clone();
-                if matches!(cloned, Some(_)) {
+                let cloned = value;
+                if cloned.is_some() {
                     right_block.free += 1;
                 }
 
@@ -128,7 +128,7 @@ impl crate::aoc::Day for Day {
                 looked_up_ids.insert(target[index_right].used[0]);
             }
 
-            if let Some(value) = target[index_right].used.get(0) {
+            if let Some(value) = target[index_right].used.front() {
                 if looked_up_ids.contains(value) {
                     highest_right = index_right;
                     lowest_left = -1;
@@ -137,12 +137,12 @@ impl crate::aoc::Day for Day {
             }
 
             if target[index_left].free >= target[index_right].used.len() {
-                while target[index_left].free > 0 && target[index_right].used.len() > 0 {
+                while target[index_left].free > 0 && !target[index_right].used.is_empty() {
                     let left_value = {
                         let right_block = &mut target[index_right];
                         let value = right_block.used.pop_back();
-                        let cloned = value.clone();
-                        if matches!(cloned, Some(_)) {
+                        let cloned = value;
+                        if cloned.is_some() {
                             right_block.free += 1;
                         }
 
@@ -167,7 +167,7 @@ impl crate::aoc::Day for Day {
         let mut counter = 0;
         for block in target.iter() {
             for block_id in block.used.iter() {
-                sum += (counter as u64) * (*block_id as u64);
+                sum += counter * (*block_id as u64);
                 counter += 1;
             }
             counter += block.free as u64;
@@ -1,9 +1,7 @@
-use std::cell::RefCell;
-use std::collections::{HashSet};
-use std::rc::Rc;
+use std::collections::HashMap;
 use std::str::FromStr;
 use crate::utils::grid::Grid;
-use crate::utils::tree::Node;
+use crate::utils::tree::{Graph, NodeId};
 
 #[derive(Default, Clone)]
 pub struct Day {
@@ -16,11 +14,10 @@ impl FromStr for Day {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut width = 0;
         let grid = s.lines()
-            .map(|line| {
+            .flat_map(|line| {
                 width = line.len();
                 line.chars().map(|c| c.to_digit(10).unwrap_or(12)).collect::<Vec<u32>>()
             })
-            .flatten()
             .collect::<Vec<u32>>();
 
         let height = s.lines().count();
@@ -54,53 +51,45 @@ impl crate::aoc::Day for Day {
     }
 
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
-        let roots = Node::try_from_grid(&self.map, |c| *c == 0, |root, node, _| *node == *root + 1, false);
-        let mut visited = HashSet::new();
+        let (graph, roots) = Graph::from_grid(&self.map, |c| *c == 0, |root, node, _| *node == *root + 1);
         let mut counter = 0;
 
         for root in roots {
-            let mut stack = vec![root];
-            while let Some(node) = stack.pop() {
-                if visited.contains(&node.as_ptr()) {
-                    continue;
-                }
-                visited.insert(node.as_ptr());
-                let borrowed = node.borrow();
-
-                if borrowed.value == 9 {
-                    counter += 1;
-                }
-                if !borrowed.children.is_empty() {
-                    stack.extend(borrowed.children.iter().map(|c| c.clone()));
-                }
-            }
+            counter += graph.bfs(root).into_iter().filter(|node| *graph.value(*node) == 9).count();
         }
 
-        Ok(counter)
+        Ok(counter as u64)
     }
 
     fn solution2(&mut self) -> anyhow::Result<Self::Output> {
-        let roots = Node::try_from_grid(&self.map, |c| *c == 0, |root, node, _| *node == *root + 1, true);
+        let (graph, roots) = Graph::from_grid(&self.map, |c| *c == 0, |root, node, _| *node == *root + 1);
+        let mut trails = HashMap::new();
         let mut distinct_paths = 0;
 
         for root in roots {
-            distinct_paths += count_individual_trails(&root);
+            distinct_paths += count_individual_trails(&graph, root, &mut trails);
         }
 
         Ok(distinct_paths as u64)
     }
 }
 
-fn count_individual_trails(current_node: &Rc<RefCell<Node<u32>>>) -> usize {
-    if current_node.borrow().value == 9 {
+/// Number of trails from `current_node` to a 9, the heights strictly increase so the graph has no cycles.
+fn count_individual_trails(graph: &Graph<u32>, current_node: NodeId, trails: &mut HashMap<NodeId, usize>) -> usize {
+    if *graph.value(current_node) == 9 {
         return 1;
     }
 
+    if let Some(count) = trails.get(&current_node) {
+        return *count;
+    }
+
     let mut total_paths = 0;
 
-    for child in current_node.borrow().children.iter() {
-        total_paths += count_individual_trails(child);
+    for child in graph.children(current_node) {
+        total_paths += count_individual_trails(graph, *child, trails);
     }
 
+    trails.insert(current_node, total_paths);
     total_paths
-}
\ No newline at end of file
+}
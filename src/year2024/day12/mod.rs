@@ -11,17 +11,7 @@ impl FromStr for Day {
     type Err = crate::aoc::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut width = 0;
-        let grid = s.lines()
-            .map(|line| {
-                width = line.len();
-                line.chars().collect::<Vec<char>>()
-            })
-            .flatten()
-            .collect::<Vec<char>>();
-
-        let height = s.lines().count();
-        Ok(Self { grid: Grid::new(width, height, grid) })
+        Ok(Self { grid: Grid::from_raw_input(s)? })
     }
 }
 
@@ -96,11 +86,11 @@ fn amount_sides(segment: &Vec<(isize, isize)>) -> usize {
             (bottom_left_neighbour, bottom_neighbour, left_neighbour),
             (bottom_right_neighbour, bottom_neighbour, right_neighbour),
         ].iter() {
-            if !segment.contains(&component_1) && !segment.contains(&component_2) && !segment.contains(&diagonal_pos) {
+            if !segment.contains(component_1) && !segment.contains(component_2) && !segment.contains(diagonal_pos) {
                 corner_count += 1;
             }
 
-            if segment.contains(&component_1) && segment.contains(&component_2) && !segment.contains(&diagonal_pos) {
+            if segment.contains(component_1) && segment.contains(component_2) && !segment.contains(diagonal_pos) {
                 corner_count += 1;
             }
         }
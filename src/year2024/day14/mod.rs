@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use crate::utils::grid::{self, Offset};
 
 #[derive(Default, Clone, Debug)]
 struct Robot {
@@ -6,6 +7,16 @@ struct Robot {
     velocity: (i32, i32),
 }
 
+impl Robot {
+    fn position_after(&self, time: i32, width: i32, height: i32) -> (i32, i32) {
+        let start = (self.position.0 as usize, self.position.1 as usize);
+        let velocity = Offset::new(self.velocity.0 as isize, self.velocity.1 as isize);
+        let (x, y) = grid::step_wrapping(start, velocity * time as isize, width as usize, height as usize);
+
+        (x as i32, y as i32)
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct Day {
     robots: Vec<Robot>,
@@ -70,21 +81,13 @@ p=9,5 v=-3,-3"#, 12)]
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
         let mut results = vec![];
         for robot in &self.robots {
-            let mut new_position = ((robot.position.0 + robot.velocity.0 * self.time) % self.width, (robot.position.1 + robot.velocity.1 * self.time) % self.height);
-            if new_position.0 < 0 {
-                new_position.0 += self.width;
-            }
-            if new_position.1 < 0 {
-                new_position.1 += self.height;
-            }
-
-            results.push(new_position);
+            results.push(robot.position_after(self.time, self.width, self.height));
         }
 
         let vertical_mid = (self.height - 1) / 2;
         let horizontal_mid = (self.width - 1) / 2;
 
-        let mut quads = vec![0; 4];
+        let mut quads = [0; 4];
         for (x, y) in results {
             if x == horizontal_mid || y == vertical_mid {
                 continue;
@@ -105,7 +108,7 @@ p=9,5 v=-3,-3"#, 12)]
             }
         }
 
-        Ok(quads.iter().fold(1, |acc, x| acc * x) as u64)
+        Ok(quads.iter().product::<i32>() as u64)
     }
 
     fn solution2(&mut self) -> anyhow::Result<Self::Output> {
@@ -115,21 +118,13 @@ p=9,5 v=-3,-3"#, 12)]
         for time in 0..(self.width * self.height) {
             let mut results = vec![];
             for robot in &self.robots {
-                let mut new_position = ((robot.position.0 + robot.velocity.0 * time) % self.width, (robot.position.1 + robot.velocity.1 * time) % self.height);
-                if new_position.0 < 0 {
-                    new_position.0 += self.width;
-                }
-                if new_position.1 < 0 {
-                    new_position.1 += self.height;
-                }
-
-                results.push(new_position);
+                results.push(robot.position_after(time, self.width, self.height));
             }
 
             let vertical_mid = (self.height - 1) / 2;
             let horizontal_mid = (self.width - 1) / 2;
 
-            let mut quads = vec![0; 4];
+            let mut quads = [0; 4];
             for (x, y) in results {
                 if x == horizontal_mid || y == vertical_mid {
                     continue;
@@ -150,7 +145,7 @@ p=9,5 v=-3,-3"#, 12)]
                 }
             }
 
-            let distribution_factor = quads.iter().fold(1, |acc, x| acc * x) as u64;
+            let distribution_factor = quads.iter().product::<i32>() as u64;
             if distribution_factor < minimum_safety_factor {
                 minimum_safety_factor = distribution_factor;
                 best_iteration = time;
@@ -50,11 +50,10 @@ impl FromStr for Day {
         }
 
         let mut width = 0;
-        let grid = grid_str.iter().map(|line| {
+        let grid = grid_str.iter().flat_map(|line| {
             width = line.len();
-            line.chars().map(|c| c.into()).collect::<Vec<char>>()
+            line.chars().collect::<Vec<char>>()
         })
-            .flatten()
             .collect::<Vec<char>>();
         let height = grid_str.len();
 
@@ -244,7 +243,7 @@ vv"#, 1)*/]
                         let mut look_at = VecDeque::new();
                         let mut seen = HashSet::new();
                         for current_box_position in &keep_track {
-                            look_at.push_back(current_box_position.clone());
+                            look_at.push_back(*current_box_position);
                         }
 
                         while let Some(current_box_fragment) = look_at.pop_front() {
@@ -266,7 +265,7 @@ vv"#, 1)*/]
                                     break;
                                 }
 
-                                if *a == '.' && look_at.len() == 0 {
+                                if *a == '.' && look_at.is_empty() {
                                     break;
                                 }
                             }
@@ -301,14 +300,14 @@ vv"#, 1)*/]
                 if keep_track.len() % 2 != 0 {
                     let mut pairs: HashSet<((usize, usize), (usize, usize))> = HashSet::new();
                     for p in &keep_track {
-                        if let Some('[') = grid.get(p.clone()) {
+                        if let Some('[') = grid.get(*p) {
                             if let Some(other_fragment) = keep_track.iter().find(|a| a.0 == p.0 + 1 && a.1 == p.1) {
-                                pairs.insert((p.clone(), other_fragment.clone()));
+                                pairs.insert((*p, *other_fragment));
                             }
                         }
-                        if let Some(']') = grid.get(p.clone()) {
+                        if let Some(']') = grid.get(*p) {
                             if let Some(other_fragment) = keep_track.iter().find(|a| a.0 == p.0 - 1 && a.1 == p.1) {
-                                pairs.insert((p.clone(), other_fragment.clone()));
+                                pairs.insert((*p, *other_fragment));
                             }
                         }
                     }
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use std::str::FromStr;
-use crate::utils::a_star_impl::{a_star, a_star_all_paths, Direction, StateDirection};
-use crate::utils::grid::Grid;
+use crate::utils::a_star::{a_star, a_star_all_paths};
+use crate::utils::grid::{Coord, Direction, Distance, Grid};
 
 
 #[derive(Default, Clone)]
@@ -13,17 +13,7 @@ impl FromStr for Day {
     type Err = crate::aoc::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut width = 0;
-        let grid = s.lines()
-            .map(|line| {
-                width = line.len();
-                line.chars().map(|c| c.into()).collect::<Vec<char>>()
-            })
-            .flatten()
-            .collect::<Vec<char>>();
-
-        let height = s.lines().count();
-        Ok(Self { grid: Grid::new(width, height, grid) })
+        Ok(Self { grid: Grid::from_raw_input(s)? })
     }
 }
 
@@ -83,150 +73,60 @@ impl crate::aoc::Day for Day {
     }
 
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
-        let start_position = self.grid.find(|a| *a == 'S').ok_or(crate::aoc::Error::NoSolutionFound)?;
+        let (start, goal) = self.endpoints()?;
+        let (_, cost) = a_star(start, |state| state.position == goal, |state| heuristic(state, goal), cost, |_, g, h| g + h, |state| self.neighbours(state))
+            .ok_or(crate::aoc::Error::NoSolutionFound)?;
 
-        let start = StateDirection {
-            position: start_position,
-            direction: Direction::East,
-        };
+        Ok(cost)
+    }
 
-        let goal = self.grid.find(|a| *a == 'E').ok_or(crate::aoc::Error::NoSolutionFound)?;
+    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
+        let (start, goal) = self.endpoints()?;
+        let shortest_paths = a_star_all_paths(start, |state| state.position == goal, |state| heuristic(state, goal), cost, |_, g, h| g + h, |state| self.neighbours(state))
+            .ok_or(crate::aoc::Error::NoSolutionFound)?;
 
-        let h = |state: &StateDirection, goal: (usize, usize)| {
-            // 0
-            (state.position.0 as i32 - goal.0 as i32).abs() + (state.position.1 as i32 - goal.1 as i32).abs()
-        };
-
-        let g = |current: &StateDirection, next: &StateDirection| {
-            if current.position == next.position { 1000 } else { 1 }
-        };
-
-        let f = |_state: &StateDirection, g: i32, h: i32| g + h;
-
-        let get_neighbours = |state: &StateDirection| {
-            let mut neighbors = Vec::new();
-            let (x, y) = state.position;
-
-            match state.direction {
-                Direction::North if self.grid.in_bounds(x as isize, y as isize - 1) && self.grid[(x, y - 1)] != '#' => {
-                    neighbors.push(StateDirection { position: (x, y - 1), direction: state.direction.clone() });
-                }
-                Direction::East if self.grid.in_bounds(x as isize + 1, y as isize) && self.grid[(x + 1, y)] != '#' => {
-                    neighbors.push(StateDirection { position: (x + 1, y), direction: state.direction.clone() });
-                }
-                Direction::South if self.grid.in_bounds(x as isize, y as isize + 1) && self.grid[(x, y + 1)] != '#' => {
-                    neighbors.push(StateDirection { position: (x, y + 1), direction: state.direction.clone() });
-                }
-                Direction::West if self.grid.in_bounds(x as isize - 1, y as isize) && self.grid[(x - 1, y)] != '#' => {
-                    neighbors.push(StateDirection { position: (x - 1, y), direction: state.direction.clone() });
-                }
-                _ => {}
-            }
+        let unique_positions = shortest_paths.nodes().into_iter().map(|state| state.position).collect::<HashSet<_>>();
 
-            // Rotate clockwise
-            neighbors.push(StateDirection {
-                position: state.position,
-                direction: match state.direction {
-                    Direction::North => Direction::East,
-                    Direction::East => Direction::South,
-                    Direction::South => Direction::West,
-                    Direction::West => Direction::North,
-                },
-            });
-
-            // Rotate counterclockwise
-            neighbors.push(StateDirection {
-                position: state.position,
-                direction: match state.direction {
-                    Direction::North => Direction::West,
-                    Direction::East => Direction::North,
-                    Direction::South => Direction::East,
-                    Direction::West => Direction::South,
-                },
-            });
-
-            neighbors
-        };
-
-        let (_, cost) = &a_star(start, goal, h, g, f, get_neighbours).ok_or(crate::aoc::Error::NoSolutionFound)?;
-        Ok(*cost)
+        Ok(unique_positions.len() as i32)
     }
+}
 
-    fn solution2(&mut self) -> anyhow::Result<Self::Output> {
-        // instead of using came_from, the state itself stores the previous state
-        let start_position = self.grid.find(|a| *a == 'S').ok_or(crate::aoc::Error::NoSolutionFound)?;
-
-        let start = StateDirection {
-            position: start_position,
-            direction: Direction::East,
-        };
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct StateDirection {
+    position: Coord,
+    direction: Direction,
+}
 
+impl Day {
+    /// The reindeer starts on `S` facing East and has to reach `E` in any direction.
+    fn endpoints(&self) -> Result<(StateDirection, Coord), crate::aoc::Error> {
+        let start_position = self.grid.find(|a| *a == 'S').ok_or(crate::aoc::Error::NoSolutionFound)?;
         let goal = self.grid.find(|a| *a == 'E').ok_or(crate::aoc::Error::NoSolutionFound)?;
 
-        let h = |state: &StateDirection, goal: (usize, usize)| {
-            (state.position.0 as i32 - goal.0 as i32).abs() + (state.position.1 as i32 - goal.1 as i32).abs()
-        };
-
-        let g = |current: &StateDirection, next: &StateDirection| {
-            if current.position == next.position { 1000 } else { 1 }
-        };
-
-        let f = |_state: &StateDirection, g: i32, h: i32| g + h;
-
-        let get_neighbours = |state: &StateDirection| {
-            let mut neighbors = Vec::new();
-            let (x, y) = state.position;
-
-            match state.direction {
-                Direction::North if self.grid.in_bounds(x as isize, y as isize - 1) && self.grid[(x, y - 1)] != '#' => {
-                    neighbors.push(StateDirection { position: (x, y - 1), direction: state.direction.clone() });
-                }
-                Direction::East if self.grid.in_bounds(x as isize + 1, y as isize) && self.grid[(x + 1, y)] != '#' => {
-                    neighbors.push(StateDirection { position: (x + 1, y), direction: state.direction.clone() });
-                }
-                Direction::South if self.grid.in_bounds(x as isize, y as isize + 1) && self.grid[(x, y + 1)] != '#' => {
-                    neighbors.push(StateDirection { position: (x, y + 1), direction: state.direction.clone() });
-                }
-                Direction::West if self.grid.in_bounds(x as isize - 1, y as isize) && self.grid[(x - 1, y)] != '#' => {
-                    neighbors.push(StateDirection { position: (x - 1, y), direction: state.direction.clone() });
-                }
-                _ => {}
-            }
+        Ok((StateDirection { position: start_position, direction: Direction::East }, goal))
+    }
 
-            // Rotate clockwise
-            neighbors.push(StateDirection {
-                position: state.position,
-                direction: match state.direction {
-                    Direction::North => Direction::East,
-                    Direction::East => Direction::South,
-                    Direction::South => Direction::West,
-                    Direction::West => Direction::North,
-                },
-            });
-
-            // Rotate counterclockwise
-            neighbors.push(StateDirection {
-                position: state.position,
-                direction: match state.direction {
-                    Direction::North => Direction::West,
-                    Direction::East => Direction::North,
-                    Direction::South => Direction::East,
-                    Direction::West => Direction::South,
-                },
-            });
-
-            neighbors
-        };
-
-        let (paths, _) = &a_star_all_paths(start, goal, h, g, f, get_neighbours).ok_or(crate::aoc::Error::NoSolutionFound)?;
-        let mut unique_positions = HashSet::new();
-        for path in paths {
-            for state in path {
-                unique_positions.insert(state.position);
-                self.grid[state.position] = '0';
+    /// One step forward if there is no wall, or a quarter turn in either direction.
+    fn neighbours(&self, state: &StateDirection) -> Vec<StateDirection> {
+        let mut neighbors = Vec::new();
+
+        if let Some(position) = self.grid.step(state.position, state.direction) {
+            if self.grid[position] != '#' {
+                neighbors.push(StateDirection { position, direction: state.direction });
             }
         }
 
-        Ok(unique_positions.len() as i32)
+        neighbors.push(StateDirection { position: state.position, direction: state.direction.turn_right() });
+        neighbors.push(StateDirection { position: state.position, direction: state.direction.turn_left() });
+
+        neighbors
     }
-}
\ No newline at end of file
+}
+
+fn heuristic(state: &StateDirection, goal: Coord) -> i32 {
+    state.position.manhattan_distance(&goal) as i32
+}
+
+fn cost(current: &StateDirection, next: &StateDirection) -> i32 {
+    if current.position == next.position { 1000 } else { 1 }
+}
@@ -0,0 +1,272 @@
+use std::fmt::{Display, Formatter};
+
+use thiserror::Error;
+
+pub const A_REG: usize = 0;
+pub const B_REG: usize = 1;
+pub const C_REG: usize = 2;
+
+/// Instructions a [`Computer`] executes before giving up, the puzzle programs need well below a thousand.
+pub const STEP_LIMIT: u64 = 1_000_000;
+
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("combo operand 7 is reserved, used at address {address}")]
+    ReservedOperand { address: usize },
+    #[error("program did not halt within {limit} instructions")]
+    StepLimit { limit: u64 },
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Instruction {
+    /*0*/ Adv(u8), // A    | division with combo operand, result in A
+    /*1*/ Bxl(u8), // B    | bitwise xor with literal
+    /*2*/ Bst(u8), // B    | modulo 8 with combo operand
+    /*3*/ Jnz(u8), //      | jump to instruction with literal
+    /*4*/ Bxc(u8), // B, C | bitwise xor
+    /*5*/ Out(u8), //      | combo operand
+    /*6*/ Bdv(u8), // A    | division with combo operand, result in B
+    /*7*/ Cdv(u8), // A    | division with combo operand, result in C
+}
+
+impl Instruction {
+    pub fn decode(opcode: u8, operand: u8) -> Instruction {
+        match opcode & 7 {
+            0 => Instruction::Adv(operand),
+            1 => Instruction::Bxl(operand),
+            2 => Instruction::Bst(operand),
+            3 => Instruction::Jnz(operand),
+            4 => Instruction::Bxc(operand),
+            5 => Instruction::Out(operand),
+            6 => Instruction::Bdv(operand),
+            _ => Instruction::Cdv(operand),
+        }
+    }
+}
+
+/// Name of a combo operand, as used by [`Instruction`]'s `Display`.
+fn combo_name(operand: u8) -> String {
+    match operand {
+        0..=3 => operand.to_string(),
+        4 => String::from("a"),
+        5 => String::from("b"),
+        6 => String::from("c"),
+        _ => String::from("?"),
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Instruction::Adv(operand) => write!(f, "a = a >> {}", combo_name(operand)),
+            Instruction::Bxl(operand) => write!(f, "b = b ^ {operand}"),
+            Instruction::Bst(operand) => write!(f, "b = {} % 8", combo_name(operand)),
+            Instruction::Jnz(operand) => write!(f, "jnz {operand}"),
+            Instruction::Bxc(_) => write!(f, "b = b ^ c"),
+            Instruction::Out(operand) => write!(f, "out {} % 8", combo_name(operand)),
+            Instruction::Bdv(operand) => write!(f, "b = a >> {}", combo_name(operand)),
+            Instruction::Cdv(operand) => write!(f, "c = a >> {}", combo_name(operand)),
+        }
+    }
+}
+
+/// Renders `program` as one readable instruction per line, prefixed with its address.
+pub fn disassemble(program: &[u8]) -> String {
+    program.chunks_exact(2)
+        .enumerate()
+        .map(|(index, pair)| format!("{:>3}: {}", index * 2, Instruction::decode(pair[0], pair[1])))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// What a single [`Computer::step`] did.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum State {
+    Running,
+    Output(u8),
+    Halted,
+}
+
+/// One executed instruction, recorded by [`Computer::trace`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trace {
+    pub address: usize,
+    pub instruction: Instruction,
+    /// Registers after the instruction.
+    pub registers: [u64; 3],
+    pub output: Option<u8>,
+}
+
+/// The 3-bit computer of 2024 Day 17.
+#[derive(Debug, Clone)]
+pub struct Computer {
+    registers: [u64; 3],
+    instruction_pointer: usize,
+    program: Vec<u8>,
+    executed: u64,
+    step_limit: u64,
+}
+
+impl Computer {
+    /// A computer that fails with [`Error::StepLimit`] after [`STEP_LIMIT`] instructions.
+    pub fn new(registers: [u64; 3], program: Vec<u8>) -> Self {
+        Self { registers, instruction_pointer: 0, program, executed: 0, step_limit: STEP_LIMIT }
+    }
+
+    pub fn with_step_limit(mut self, limit: u64) -> Self {
+        self.step_limit = limit;
+        self
+    }
+
+    pub fn registers(&self) -> [u64; 3] {
+        self.registers
+    }
+
+    /// Executes one instruction. Reading an opcode or operand past the end of the program halts.
+    pub fn step(&mut self) -> Result<State, Error> {
+        let Some(instruction) = self.fetch(self.instruction_pointer) else {
+            return Ok(State::Halted);
+        };
+
+        if self.executed >= self.step_limit {
+            return Err(Error::StepLimit { limit: self.step_limit });
+        }
+
+        let mut state = State::Running;
+        let mut next = self.instruction_pointer + 2;
+
+        match instruction {
+            Instruction::Adv(operand) => self.registers[A_REG] = self.divide(operand)?,
+            Instruction::Bxl(operand) => self.registers[B_REG] ^= operand as u64,
+            Instruction::Bst(operand) => self.registers[B_REG] = self.combo(operand)? % 8,
+            Instruction::Jnz(operand) => if self.registers[A_REG] != 0 {
+                next = operand as usize;
+            },
+            Instruction::Bxc(_) => self.registers[B_REG] ^= self.registers[C_REG],
+            Instruction::Out(operand) => state = State::Output((self.combo(operand)? % 8) as u8),
+            Instruction::Bdv(operand) => self.registers[B_REG] = self.divide(operand)?,
+            Instruction::Cdv(operand) => self.registers[C_REG] = self.divide(operand)?,
+        }
+
+        self.instruction_pointer = next;
+        self.executed += 1;
+        Ok(state)
+    }
+
+    /// Runs until the program halts and returns everything it printed.
+    pub fn run(&mut self) -> Result<Vec<u8>, Error> {
+        self.outputs().collect()
+    }
+
+    /// The printed values, computed lazily.
+    pub fn outputs(&mut self) -> Outputs<'_> {
+        Outputs { computer: self }
+    }
+
+    /// Runs until the program halts, recording every executed instruction.
+    pub fn trace(&mut self) -> Result<Vec<Trace>, Error> {
+        let mut trace = Vec::new();
+
+        loop {
+            let address = self.instruction_pointer;
+
+            let output = match self.step()? {
+                State::Running => None,
+                State::Output(value) => Some(value),
+                State::Halted => return Ok(trace),
+            };
+
+            let instruction = self.fetch(address).expect("step executed an instruction at this address");
+            trace.push(Trace { address, instruction, registers: self.registers, output });
+        }
+    }
+
+    /// The instruction at `address`, if both its opcode and operand are inside the program.
+    fn fetch(&self, address: usize) -> Option<Instruction> {
+        Some(Instruction::decode(*self.program.get(address)?, *self.program.get(address + 1)?))
+    }
+
+    fn combo(&self, operand: u8) -> Result<u64, Error> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.registers[A_REG]),
+            5 => Ok(self.registers[B_REG]),
+            6 => Ok(self.registers[C_REG]),
+            _ => Err(Error::ReservedOperand { address: self.instruction_pointer }),
+        }
+    }
+
+    /// `A / 2^combo`, a shift so large operands yield 0 instead of overflowing.
+    fn divide(&self, operand: u8) -> Result<u64, Error> {
+        let shift = self.combo(operand)?;
+        Ok(u32::try_from(shift).ok().and_then(|shift| self.registers[A_REG].checked_shr(shift)).unwrap_or(0))
+    }
+}
+
+/// Iterator returned by [`Computer::outputs`].
+pub struct Outputs<'a> {
+    computer: &'a mut Computer,
+}
+
+impl Iterator for Outputs<'_> {
+    type Item = Result<u8, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.computer.step() {
+                Ok(State::Running) => {}
+                Ok(State::Output(value)) => return Some(Ok(value)),
+                Ok(State::Halted) => return None,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+/// The lowest value of register A that makes `program` print itself, `None` if there is none.
+///
+/// Expects the usual shape of the puzzle: a loop that prints one value and shifts A right by 3 bits per iteration, so
+/// the last printed value only depends on the highest octal digit of A. A is rebuilt digit by digit, starting with
+/// the highest, keeping only candidates whose output matches the end of the program. Programs of any other shape
+/// have no solution found this way and give `None` without being run.
+pub fn find_quine(program: &[u8], registers: [u64; 3]) -> Result<Option<u64>, Error> {
+    fn extend(program: &[u8], registers: [u64; 3], a: u64, matched: usize) -> Result<Option<u64>, Error> {
+        if matched == program.len() {
+            return Ok(Some(a));
+        }
+
+        for digit in 0..8 {
+            let candidate = a << 3 | digit;
+            let output = Computer::new([candidate, registers[B_REG], registers[C_REG]], program.to_vec()).run()?;
+
+            if output == program[program.len() - matched - 1..] {
+                if let Some(a) = extend(program, registers, candidate, matched + 1)? {
+                    return Ok(Some(a));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    if !is_shift_loop(program) {
+        return Ok(None);
+    }
+
+    extend(program, registers, 0, 0)
+}
+
+/// Whether `program` is a single loop that ends with `jnz 0`, shifts A right by 3 once and prints once.
+fn is_shift_loop(program: &[u8]) -> bool {
+    if !program.len().is_multiple_of(2) {
+        return false;
+    }
+
+    let instructions = program.chunks_exact(2).map(|pair| Instruction::decode(pair[0], pair[1])).collect::<Vec<_>>();
+    let all = |matches: fn(&Instruction) -> bool| instructions.iter().copied().filter(matches).collect::<Vec<_>>();
+
+    instructions.last() == Some(&Instruction::Jnz(0))
+        && all(|instruction| matches!(instruction, Instruction::Jnz(_))).len() == 1
+        && all(|instruction| matches!(instruction, Instruction::Out(_))).len() == 1
+        && all(|instruction| matches!(instruction, Instruction::Adv(_))) == [Instruction::Adv(3)]
+}
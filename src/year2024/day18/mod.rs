@@ -1,6 +1,6 @@
 use std::str::FromStr;
 use itertools::Itertools;
-use crate::utils::a_star_impl::{a_star, a_star_in_place_grid, Direction, StateDirection};
+use crate::utils::a_star::a_star;
 use crate::utils::grid::{Coord, Distance, Grid};
 
 #[derive(Default, Clone, Debug)]
@@ -100,48 +100,7 @@ impl crate::aoc::Day for Day {
             grid[(*x, *y)] =  '#';
         });
 
-        let start = StateDirection {
-            position: (0, 0),
-            direction: Direction::North,
-        };
-
-        let h = |state: &StateDirection, goal: (usize, usize)| {
-            // 0
-            (state.position.0 as i32 - goal.0 as i32).abs() + (state.position.1 as i32 - goal.1 as i32).abs()
-        };
-
-        let g = |current: &StateDirection, next: &StateDirection| {
-            current.position.manhattan_distance(&next.position) as i32
-        };
-
-        let f = |_state: &StateDirection, g: i32, h: i32| g + h;
-
-        let get_neighbours = |state: &StateDirection| {
-            let mut neighbors = Vec::new();
-            let (x, y) = state.position;
-
-            if grid.in_bounds(x as isize, y as isize - 1) && grid[(x, y - 1)] != '#' {
-                neighbors.push(StateDirection { position: (x, y - 1), direction: state.direction.clone() });
-            }
-            if grid.in_bounds(x as isize + 1, y as isize) && grid[(x + 1, y)] != '#' {
-                neighbors.push(StateDirection { position: (x + 1, y), direction: state.direction.clone() });
-            }
-            if grid.in_bounds(x as isize, y as isize + 1) && grid[(x, y + 1)] != '#' {
-                neighbors.push(StateDirection { position: (x, y + 1), direction: state.direction.clone() });
-            }
-            if grid.in_bounds(x as isize - 1, y as isize) && grid[(x - 1, y)] != '#' {
-                neighbors.push(StateDirection { position: (x - 1, y), direction: state.direction.clone() });
-            }
-
-            neighbors
-        };
-
-
-
-        let end = ((self.width as i32 - 1) as usize, (self.height as i32 - 1) as usize);
-
-        let result = a_star(start, end, h, g, f, get_neighbours);
-        if let Some((_, g_cost)) = result {
+        if let Some((_, g_cost)) = self.shortest_path(&grid) {
             return Ok(g_cost.to_string());
         }
 
@@ -155,30 +114,11 @@ impl crate::aoc::Day for Day {
             grid[(*x, *y)] =  '#';
         });
 
-        let start = StateDirection {
-            position: (0, 0),
-            direction: Direction::North,
-        };
-        let end = ((self.width as i32 - 1) as usize, (self.height as i32 - 1) as usize);
-
-        let h = |state: &StateDirection, goal: (usize, usize)| {
-            // 0
-            (state.position.0 as i32 - goal.0 as i32).abs() + (state.position.1 as i32 - goal.1 as i32).abs()
-        };
-
-        let g = |current: &StateDirection, next: &StateDirection| {
-            current.position.manhattan_distance(&next.position) as i32
-        };
-
-        let f = |_state: &StateDirection, g: i32, h: i32| g + h;
-
-
-        let mut iter = self.byte_positions.iter().skip(self.amount_bytes);
-        while let Some((x, y)) = iter.next() {
+        let iter = self.byte_positions.iter().skip(self.amount_bytes);
+        for (x, y) in iter {
             grid[(*x, *y)] = '#';
 
-            let result = a_star_in_place_grid(start.clone(), end, h, g, f, &grid);
-            if let None = result {
+            if self.shortest_path(&grid).is_none() {
                 return Ok(format!("{},{}", x, y));
             }
         }
@@ -186,4 +126,27 @@ impl crate::aoc::Day for Day {
 
         Ok("".to_string())
     }
-}
\ No newline at end of file
+}
+
+impl Day {
+    /// Shortest path from the top left to the bottom right corner around the fallen bytes.
+    fn shortest_path(&self, grid: &Grid<char>) -> Option<(Vec<Coord>, usize)> {
+        let end = (self.width - 1, self.height - 1);
+
+        let get_neighbours = |position: &Coord| {
+            grid.neighbours_4(*position)
+                .filter(|(_, value)| **value != '#')
+                .map(|(position, _)| position)
+                .collect()
+        };
+
+        a_star(
+            (0, 0),
+            |position| *position == end,
+            |position| position.manhattan_distance(&end),
+            |current, next| current.manhattan_distance(next),
+            |_, g, h| g + h,
+            get_neighbours,
+        )
+    }
+}
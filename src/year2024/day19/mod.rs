@@ -90,7 +90,7 @@ fn contains_count(target: &str, patterns: &Vec<String>, cache: &mut HashMap<Stri
     let mut count = 0;
     for i in 0..(target.len().min(patterns.len()) + 1) {
         if patterns.contains(&target[..i].to_string()) {
-            let inner_count = contains_count(&target[i..], patterns, cache);;
+            let inner_count = contains_count(&target[i..], patterns, cache);
             cache.insert(target[i..].to_string(), inner_count);
             count += contains_count(&target[i..], patterns, cache);
         }
@@ -70,7 +70,7 @@ pub fn has_repeating_sequence(num: &str) -> bool {
     let n = bytes.len();
 
     for block_len in 1..=n / 2 {
-        if n % block_len != 0 {
+        if !n.is_multiple_of(block_len) {
             continue;
         }
 
@@ -100,7 +100,7 @@ pub fn has_repeating_sequence(num: &str) -> bool {
 
 pub fn has_repeating_sequence_twice(num: &str) -> bool {
     let n = num.len();
-    if n % 2 != 0 {
+    if !n.is_multiple_of(2) {
         return false;
     }
 
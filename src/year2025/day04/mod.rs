@@ -11,7 +11,7 @@ impl FromStr for Day {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            grid: Grid::<char>::from_raw_input(s)
+            grid: Grid::<char>::from_raw_input(s)?
         })
     }
 }
@@ -41,7 +41,8 @@ impl FromStr for Day {
             }
         }
 
-        let grid = Grid::parse_to_u8(s);
+        // the worksheet lines are not always padded to the same width
+        let grid = Grid::parse_padded(s, |c| c as u8, b' ');
 
 
 
@@ -1,5 +1,7 @@
 use std::str::FromStr;
+use crate::aoc::Context;
 use crate::utils::grid::{Vec3};
+use crate::utils::parse;
 
 #[derive(Debug, Default, Clone)]
 pub struct Day {
@@ -13,15 +15,9 @@ impl FromStr for Day {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             positions: s.lines()
-                .map(|l| {
-                    let coords: Vec<i64> = l
-                        .split(',')
-                        .map(|n| n.parse::<i64>().map_err(|e| crate::aoc::Error::Parse(e.to_string())))
-                        .collect::<Result<Vec<i64>, crate::aoc::Error>>()?;
-                    if coords.len() != 3 {
-                        return Err(crate::aoc::Error::Parse("Expected 3 coordinates per line".to_string()));
-                    }
-                    Ok(Vec3::new(coords[0], coords[1], coords[2]))
+                .map(|l| match parse::list::<i64>(l, ',').within(s, l)?[..] {
+                    [x, y, z] => Ok(Vec3::new(x, y, z)),
+                    _ => Err(crate::aoc::Error::at("expected 3 coordinates per line", s, l)),
                 }).collect::<Result<Vec<Vec3>, crate::aoc::Error>>()?,
             limit: 10,
         })
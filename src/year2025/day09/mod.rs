@@ -1,4 +1,4 @@
-use crate::utils::grid::{Coord, Grid};
+use crate::utils::grid::{Coord, Direction, Grid};
 use itertools::Itertools;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::str::FromStr;
@@ -104,9 +104,8 @@ impl crate::aoc::Day for Day {
 
 
         while let Some(point) = queue.pop_front() {
-            for next in [(0, -1), (0, 1), (-1, 0), (1, 0)].map(|o| (point.0 as isize + o.0, point.1 as isize + o.1)) {
-                if grid.in_bounds(next.0, next.1) {
-                    let next = Coord::from((next.0 as usize, next.1 as usize));
+            for direction in Direction::CARDINAL {
+                if let Some(next) = grid.step(point, direction) {
                     if grid[next] == b'X' {
                         grid[next] = b'.';
                         queue.push_back(next);
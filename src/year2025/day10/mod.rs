@@ -9,6 +9,7 @@ pub struct Day {
 struct Machine {
     buttons: Vec<Vec<usize>>, // list of indices toggled by each button
     light_indicators: Vec<u8>, // 0/1 vector desired
+    #[allow(dead_code)]
     joultages: Vec<u32>
 }
 
@@ -147,6 +148,7 @@ impl crate::aoc::Day for Day {
         vec![]
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn solution1(&mut self) -> anyhow::Result<Self::Output> {
         let mut total: u64 = 0;
 
@@ -0,0 +1,55 @@
+//! Shortest path DAGs built by `a_star_all_paths` on small hand-made graphs.
+
+use std::collections::HashSet;
+
+use advent_of_code::utils::a_star::a_star_all_paths;
+
+/// Directed edges `(from, to, cost)`.
+fn search(edges: &[(char, char, u32)], start: char, goal: char) -> Option<advent_of_code::utils::a_star::ShortestPaths<char, u32>> {
+    a_star_all_paths(
+        start,
+        |&state| state == goal,
+        |_| 0,
+        |&from, &to| edges.iter().find(|&&(a, b, _)| a == from && b == to).map(|&(_, _, cost)| cost).unwrap(),
+        |_, g, h| g + h,
+        |&state| edges.iter().filter(|&&(from, _, _)| from == state).map(|&(_, to, _)| to).collect(),
+    )
+}
+
+#[test]
+fn diamond_has_two_shortest_paths() {
+    // s -> a -> g and s -> b -> g both cost 2, s -> c -> g costs 3
+    let edges = [('s', 'a', 1), ('s', 'b', 1), ('a', 'g', 1), ('b', 'g', 1), ('s', 'c', 1), ('c', 'g', 2)];
+    let shortest = search(&edges, 's', 'g').unwrap();
+
+    assert_eq!(shortest.cost, 2);
+    assert_eq!(shortest.count_paths(), 2);
+    assert_eq!(shortest.nodes(), HashSet::from(['s', 'a', 'b', 'g']));
+
+    let paths = shortest.paths().collect::<HashSet<_>>();
+    assert_eq!(paths, HashSet::from([vec!['s', 'a', 'g'], vec!['s', 'b', 'g']]));
+}
+
+#[test]
+fn diamond_with_zero_cost_last_edges_keeps_both_paths() {
+    let edges = [('s', 'a', 1), ('s', 'b', 1), ('a', 'g', 0), ('b', 'g', 0)];
+    let shortest = search(&edges, 's', 'g').unwrap();
+
+    assert_eq!(shortest.cost, 1);
+    assert_eq!(shortest.count_paths(), 2);
+    assert_eq!(shortest.nodes(), HashSet::from(['s', 'a', 'b', 'g']));
+
+    let paths = shortest.paths().collect::<HashSet<_>>();
+    assert_eq!(paths, HashSet::from([vec!['s', 'a', 'g'], vec!['s', 'b', 'g']]));
+}
+
+#[test]
+fn zero_cost_edges_do_not_create_predecessor_cycles() {
+    // a and b reach each other for free, and every state has a free self loop
+    let edges = [('s', 'a', 1), ('a', 'b', 0), ('b', 'a', 0), ('a', 'a', 0), ('b', 'b', 0), ('b', 'g', 1)];
+    let shortest = search(&edges, 's', 'g').unwrap();
+
+    assert_eq!(shortest.cost, 2);
+    assert_eq!(shortest.count_paths(), 1);
+    assert_eq!(shortest.paths().collect::<Vec<_>>(), vec![vec!['s', 'a', 'b', 'g']]);
+}
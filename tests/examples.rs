@@ -0,0 +1,59 @@
+//! Turns every example returned by `test_cases_1`/`test_cases_2` of a registered day into its own test,
+//! named `yearYYYY::dayNN::partN::example_I`, so `cargo test year2023::day19` runs a single day.
+
+use advent_of_code::aoc::Part;
+use advent_of_code::registry;
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let arguments = Arguments::from_args();
+
+    let trials = registry::all()
+        .into_iter()
+        .flat_map(|entry| Part::ALL.into_iter().map(move |part| (entry, part)))
+        .flat_map(|(entry, part)| {
+            entry.examples(part).into_iter().enumerate().map(move |(index, example)| {
+                let name = format!("year{}::day{:02}::part{part}::example_{index}", entry.year, entry.day);
+
+                Trial::test(name, move || {
+                    let actual = entry.solve_example(part, example.input).map_err(|error| Failed::from(format!("{error:?}")))?;
+
+                    if actual == example.expected {
+                        Ok(())
+                    } else {
+                        Err(diff(&example.expected, &actual).into())
+                    }
+                })
+            }).collect::<Vec<_>>()
+        })
+        .collect();
+
+    libtest_mimic::run(&arguments, trials).exit();
+}
+
+/// Renders expected vs actual output, line by line for multi-line answers like the 2022 Day 10 screen.
+fn diff(expected: &str, actual: &str) -> String {
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return format!("expected: {expected}\n  actual: {actual}");
+    }
+
+    let expected_lines = expected.lines().collect::<Vec<_>>();
+    let actual_lines = actual.lines().collect::<Vec<_>>();
+    let mut result = String::from("--- expected\n+++ actual\n");
+
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => result += &format!(" {expected}\n"),
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    result += &format!("-{expected}\n");
+                }
+                if let Some(actual) = actual {
+                    result += &format!("+{actual}\n");
+                }
+            }
+        }
+    }
+
+    result
+}
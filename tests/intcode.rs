@@ -0,0 +1,77 @@
+//! The shared IntCode VM, its search helper, disassembler and tracer on small programs.
+
+use advent_of_code::utils::intcode::disassembler::disassemble;
+use advent_of_code::utils::intcode::tracer::Event;
+use advent_of_code::utils::intcode::{self, Error, IntCodeComputer, Status};
+
+#[test]
+fn search_inputs_skips_candidates_that_fault() {
+    // the candidate is written over the first opcode, only `1` (add) leaves `2` in memory, most others are unknown
+    let program = [0, 0, 0, 0, 99];
+    let is_target = |computer: &mut IntCodeComputer| computer.is_halted() && computer.peek(0) == 2;
+
+    let found = intcode::search_inputs(&program, (1..50).collect::<Vec<i64>>(), |computer, &opcode| computer.poke(0, opcode), is_target);
+    assert_eq!(found, Some(1));
+
+    let found = intcode::search_inputs(&program, vec![50, 77], |computer, &opcode| computer.poke(0, opcode), is_target);
+    assert_eq!(found, None);
+}
+
+#[test]
+fn disassembles_every_parameter_mode_and_trailing_data() {
+    let program = [1001, 12, 5, 20, 1101, 12, 5, 20, 3, 9, 109, -3, 204, 2, 21201, -1, 7, 3, 99, 42, 7];
+
+    let expected = [
+        "    0: 1001,12,5,20       ADD [12], #5 -> [20]",
+        "    4: 1101,12,5,20       ADD #12, #5 -> [20]",
+        "    8: 3,9                IN -> [9]",
+        "   10: 109,-3             ARB #-3",
+        "   12: 204,2              OUT [rb+2]",
+        "   14: 21201,-1,7,3       ADD [rb-1], #7 -> [rb+3]",
+        "   18: 99                 HALT",
+        "   19: 42                 DATA 42",
+        "   20: 7                  DATA 7",
+    ];
+    assert_eq!(disassemble(&program), expected.join("\n"));
+}
+
+#[test]
+fn tracer_keeps_the_newest_steps_until_the_step_limit() {
+    // adds forever: `[20] = 1 + 0`, then jumps back to 0
+    let mut computer = IntCodeComputer::new(vec![1101, 1, 0, 20, 1105, 1, 0]);
+    computer.enable_trace(3);
+    computer.limit_steps(7);
+
+    assert_eq!(computer.run(), Err(Error::StepLimit { address: 4, limit: 7 }));
+    assert_eq!(computer.executed(), 7);
+
+    let tracer = computer.trace().unwrap();
+    assert_eq!(tracer.executed(), 7);
+    assert_eq!(tracer.steps().map(|step| step.index).collect::<Vec<_>>(), vec![4, 5, 6]);
+    assert_eq!(tracer.steps().map(|step| step.instruction.address).collect::<Vec<_>>(), vec![0, 4, 0]);
+
+    let lines = tracer.to_string();
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(lines, vec![
+        "#4            0: ADD #1, #0 -> [20]               ; 1, 0, 20 ; [20] = 1",
+        "#5            4: JT #1, #0                        ; 1, 0",
+        "#6            0: ADD #1, #0 -> [20]               ; 1, 0, 20 ; [20] = 1",
+    ]);
+}
+
+#[test]
+fn tracer_records_input_and_output() {
+    let mut computer = IntCodeComputer::new(vec![3, 0, 4, 0, 99]);
+    computer.enable_trace(10);
+
+    assert_eq!(computer.run(), Ok(Status::NeedsInput));
+    assert_eq!(computer.trace().unwrap().steps().count(), 0);
+
+    computer.push_input(42);
+    assert_eq!(computer.run(), Ok(Status::Halted));
+    assert_eq!(computer.take_output(), vec![42]);
+
+    let events = computer.trace().unwrap().steps().map(|step| step.event).collect::<Vec<_>>();
+    assert_eq!(events, vec![Some(Event::Input(42)), Some(Event::Output(42)), None]);
+    assert_eq!(computer.trace().unwrap().steps().next().unwrap().writes, vec![(0, 42)]);
+}
@@ -0,0 +1,68 @@
+//! IntCode machines wired together by the network scheduler.
+
+use advent_of_code::utils::intcode::network::{Network, Outcome, Packet, Schedule};
+use advent_of_code::utils::intcode::IntCodeComputer;
+
+/// The amplifier feedback loop example of 2019 Day 07, phase settings `9,8,7,6,5` give 139629729.
+const FEEDBACK_LOOP: [i64; 29] = [
+    3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28, 1005, 28, 6, 99, 0, 0, 5,
+];
+
+fn amplifiers(schedule: Schedule) -> Network {
+    let machines = [9, 8, 7, 6, 5].into_iter()
+        .map(|phase| {
+            let mut machine = IntCodeComputer::new(FEEDBACK_LOOP.to_vec());
+            machine.push_input(phase);
+            machine
+        })
+        .collect();
+
+    let mut network = Network::ring(machines, schedule);
+    network.push_input(0, 0);
+    network
+}
+
+#[test]
+fn feedback_loop_ring() {
+    for schedule in [Schedule::RoundRobin, Schedule::UntilBlocked] {
+        let mut network = amplifiers(schedule);
+
+        assert_eq!(network.run(), Ok(Outcome::Halted));
+        assert_eq!(network.last_output(4), Some(139629729));
+    }
+}
+
+#[test]
+fn machines_waiting_on_each_other_deadlock() {
+    // reads a value, echoes it and reads again, neither machine ever gets a first value
+    let echo = vec![3, 0, 4, 0, 1105, 1, 0];
+
+    for schedule in [Schedule::RoundRobin, Schedule::UntilBlocked] {
+        let mut network = Network::ring(vec![IntCodeComputer::new(echo.clone()), IntCodeComputer::new(echo.clone())], schedule);
+
+        assert_eq!(network.run(), Ok(Outcome::Deadlock { blocked: vec![0, 1] }));
+    }
+}
+
+#[test]
+fn addressed_packets_are_routed_until_idle() {
+    // sends `7, 8` to machine 1, then polls its empty queue forever
+    let sender = vec![3, 100, 104, 1, 104, 7, 104, 8, 3, 101, 1105, 1, 8];
+    // polls until it gets a packet and forwards it to address 255
+    let forwarder = vec![3, 100, 3, 101, 1008, 101, -1, 103, 1005, 103, 2, 3, 102, 104, 255, 4, 101, 4, 102, 1105, 1, 2];
+
+    for schedule in [Schedule::RoundRobin, Schedule::UntilBlocked] {
+        let machines = vec![IntCodeComputer::new(sender.clone()), IntCodeComputer::new(forwarder.clone())];
+        let mut network = Network::addressed(machines, schedule);
+
+        assert_eq!(network.run(), Ok(Outcome::Idle));
+        assert_eq!(network.take_packets(), vec![Packet { from: 1, to: 255, x: 7, y: 8 }]);
+        assert_eq!(network.machine(0).peek(100), 0);
+        assert_eq!(network.machine(1).peek(100), 1);
+
+        // waking the forwarder up sends the packet on again
+        network.send(1, 3, 4);
+        assert_eq!(network.run(), Ok(Outcome::Idle));
+        assert_eq!(network.take_packets(), vec![Packet { from: 1, to: 255, x: 3, y: 4 }]);
+    }
+}